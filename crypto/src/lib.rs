@@ -41,7 +41,7 @@ pub use merkle::{build_merkle_nodes, BatchMerkleProof, MerkleTree};
 pub use merkle::concurrent;
 
 mod random;
-pub use random::RandomCoin;
+pub use random::{hash_to_field, RandomCoin};
 
 mod errors;
 pub use errors::{MerkleTreeError, RandomCoinError};
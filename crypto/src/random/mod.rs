@@ -5,7 +5,7 @@
 
 use crate::{errors::RandomCoinError, Digest, Hasher};
 use core::{convert::TryInto, marker::PhantomData};
-use math::{FieldElement, StarkField};
+use math::{fields::CubeExtension, ExtensibleField, FieldElement, StarkField};
 use utils::collections::Vec;
 
 // RANDOM COIN
@@ -219,6 +219,45 @@ impl<B: StarkField, H: Hasher> RandomCoin<B, H> {
         Ok((self.draw()?, self.draw()?, self.draw()?))
     }
 
+    /// Returns the next pseudo-random element of the cubic extension of the base field.
+    ///
+    /// Each coordinate of the element is drawn independently: the coin is advanced until the
+    /// first [ELEMENT_BYTES](math::FieldElement::ELEMENT_BYTES) of hash(`seed` || `counter`) are
+    /// accepted as a valid base field element, and thus, each coordinate is distributed
+    /// uniformly in the base field. The coordinates are drawn in the order α, β, γ.
+    ///
+    /// # Errors
+    /// Returns an error if a valid base field element could not be generated for any of the
+    /// coordinates after 1000 calls to the PRNG.
+    ///
+    /// # Examples
+    /// ```
+    /// # use winter_crypto::{RandomCoin, hashers::Blake3_256};
+    /// # use math::fields::f64::BaseElement;
+    /// let mut coin1 = RandomCoin::<BaseElement, Blake3_256<BaseElement>>::new(&[1, 2, 3, 4]);
+    /// let mut coin2 = RandomCoin::<BaseElement, Blake3_256<BaseElement>>::new(&[1, 2, 3, 4]);
+    ///
+    /// // should draw the same element from both coins
+    /// let e1 = coin1.draw_cube().unwrap();
+    /// let e2 = coin2.draw_cube().unwrap();
+    /// assert_eq!(e1, e2);
+    ///
+    /// // should draw a different element next time
+    /// let e3 = coin1.draw_cube().unwrap();
+    /// assert_ne!(e1, e3);
+    /// ```
+    pub fn draw_cube(&mut self) -> Result<CubeExtension<B>, RandomCoinError>
+    where
+        B: ExtensibleField<3>,
+    {
+        // coordinates are drawn in order, since function arguments are evaluated left to right
+        Ok(CubeExtension::new(
+            self.draw::<B>()?,
+            self.draw::<B>()?,
+            self.draw::<B>()?,
+        ))
+    }
+
     /// Returns a vector of unique integers selected from the range [0, domain_size).
     ///
     /// # Errors
@@ -305,3 +344,177 @@ impl<B: StarkField, H: Hasher> RandomCoin<B, H> {
         H::merge_with_int(self.seed, self.counter)
     }
 }
+
+// HASH TO FIELD
+// ================================================================================================
+
+/// Deterministically maps the message `msg` to an element in the cubic extension of the base
+/// field `B` using hash function `H`, with the mapping separated by `domain`.
+///
+/// The mapping works as follows:
+/// - A [RandomCoin] is instantiated with len(`domain`) || `domain` || `msg` as the seed, where
+///   len(`domain`) is encoded as 8 little-endian bytes; the length prefix makes sure that
+///   different (`domain`, `msg`) pairs cannot produce the same seed.
+/// - The element is then drawn from the coin via [RandomCoin::draw_cube()].
+///
+/// # Panics
+/// Panics if a valid base field element could not be derived after 1000 attempts for any of
+/// the coordinates; for supported base fields, the probability of this is negligible.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{hash_to_field, hashers::Blake3_256};
+/// # use math::fields::{f64::BaseElement, CubeExtension};
+/// type H = Blake3_256<BaseElement>;
+///
+/// // the mapping is deterministic
+/// let e1: CubeExtension<BaseElement> = hash_to_field::<BaseElement, H>(b"label", &[1, 2, 3]);
+/// let e2: CubeExtension<BaseElement> = hash_to_field::<BaseElement, H>(b"label", &[1, 2, 3]);
+/// assert_eq!(e1, e2);
+///
+/// // different domains yield different elements
+/// let e3: CubeExtension<BaseElement> = hash_to_field::<BaseElement, H>(b"other", &[1, 2, 3]);
+/// assert_ne!(e1, e3);
+/// ```
+pub fn hash_to_field<B, H>(domain: &[u8], msg: &[u8]) -> CubeExtension<B>
+where
+    B: StarkField + ExtensibleField<3>,
+    H: Hasher,
+{
+    let mut input = Vec::with_capacity(8 + domain.len() + msg.len());
+    input.extend_from_slice(&(domain.len() as u64).to_le_bytes());
+    input.extend_from_slice(domain);
+    input.extend_from_slice(msg);
+
+    RandomCoin::<B, H>::new(&input)
+        .draw_cube()
+        .expect("failed to generate a valid field element")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_to_field, RandomCoin};
+    use crate::hashers::{Blake3_256, Rp64_256, Sha3_256};
+    use math::{
+        fields::{f62, f64, CubeExtension},
+        StarkField,
+    };
+    use utils::collections::Vec;
+
+    #[test]
+    fn hash_to_field_deterministic() {
+        type H = Blake3_256<f64::BaseElement>;
+        let e1: CubeExtension<f64::BaseElement> = hash_to_field::<_, H>(b"domain", b"msg");
+        let e2: CubeExtension<f64::BaseElement> = hash_to_field::<_, H>(b"domain", b"msg");
+        assert_eq!(e1, e2);
+
+        type R = Rp64_256;
+        let e1: CubeExtension<f64::BaseElement> = hash_to_field::<_, R>(b"domain", b"msg");
+        let e2: CubeExtension<f64::BaseElement> = hash_to_field::<_, R>(b"domain", b"msg");
+        assert_eq!(e1, e2);
+
+        type S = Sha3_256<f62::BaseElement>;
+        let e1: CubeExtension<f62::BaseElement> = hash_to_field::<_, S>(b"domain", b"msg");
+        let e2: CubeExtension<f62::BaseElement> = hash_to_field::<_, S>(b"domain", b"msg");
+        assert_eq!(e1, e2);
+    }
+
+    #[test]
+    fn hash_to_field_matches_random_coin() {
+        type B = f64::BaseElement;
+        type H = Blake3_256<B>;
+        let domain = b"domain";
+        let seed = [&(domain.len() as u64).to_le_bytes()[..], domain, b"msg"].concat();
+        let mut coin = RandomCoin::<B, H>::new(&seed);
+        assert_eq!(
+            coin.draw_cube().unwrap(),
+            hash_to_field::<B, H>(domain, b"msg")
+        );
+    }
+
+    #[test]
+    fn hash_to_field_domain_separation() {
+        type H = Blake3_256<f64::BaseElement>;
+        let e1: CubeExtension<f64::BaseElement> = hash_to_field::<_, H>(b"domain1", b"msg");
+        let e2: CubeExtension<f64::BaseElement> = hash_to_field::<_, H>(b"domain2", b"msg");
+        assert_ne!(e1, e2);
+
+        // moving bytes between the domain and the message changes the output
+        let e3: CubeExtension<f64::BaseElement> = hash_to_field::<_, H>(b"domain", b"1msg");
+        assert_ne!(e1, e3);
+
+        // different messages in the same domain yield different outputs
+        let e4: CubeExtension<f64::BaseElement> = hash_to_field::<_, H>(b"domain1", b"msg2");
+        assert_ne!(e1, e4);
+
+        // all coordinates are populated independently
+        let [a, b, c] = e1.to_array();
+        assert_ne!(a, b);
+        assert_ne!(b, c);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn draw_cube_deterministic() {
+        type B = f64::BaseElement;
+        type H = Blake3_256<B>;
+        let mut coin1 = RandomCoin::<B, H>::new(&[1, 2, 3, 4]);
+        let mut coin2 = RandomCoin::<B, H>::new(&[1, 2, 3, 4]);
+        for _ in 0..16 {
+            assert_eq!(coin1.draw_cube().unwrap(), coin2.draw_cube().unwrap());
+        }
+
+        // reseeding both coins in the same way keeps them in sync
+        coin1.reseed_with_int(42);
+        coin2.reseed_with_int(42);
+        let e1 = coin1.draw_cube().unwrap();
+        assert_eq!(e1, coin2.draw_cube().unwrap());
+
+        // the transcript advances, and diverges from a coin reseeded differently
+        let e2 = coin1.draw_cube().unwrap();
+        assert_ne!(e1, e2);
+        coin2.reseed_with_int(43);
+        assert_ne!(e2, coin2.draw_cube().unwrap());
+
+        // the same holds for other base fields and hash functions
+        let mut coin1 = RandomCoin::<f64::BaseElement, Rp64_256>::new(&[1, 2, 3, 4]);
+        let mut coin2 = RandomCoin::<f64::BaseElement, Rp64_256>::new(&[1, 2, 3, 4]);
+        assert_eq!(coin1.draw_cube().unwrap(), coin2.draw_cube().unwrap());
+
+        type S = Sha3_256<f62::BaseElement>;
+        let mut coin1 = RandomCoin::<f62::BaseElement, S>::new(&[1, 2, 3, 4]);
+        let mut coin2 = RandomCoin::<f62::BaseElement, S>::new(&[1, 2, 3, 4]);
+        assert_eq!(coin1.draw_cube().unwrap(), coin2.draw_cube().unwrap());
+    }
+
+    #[test]
+    fn draw_cube_distribution() {
+        type B = f64::BaseElement;
+        let mut coin = RandomCoin::<B, Blake3_256<B>>::new(&[1, 2, 3, 4]);
+
+        // count how many times the most significant bit of each coordinate is set; for uniformly
+        // distributed coordinates, this should happen for about half of the draws
+        let num_draws = 1000;
+        let mut values = Vec::with_capacity(num_draws);
+        let mut msb_counts = [0; 3];
+        for _ in 0..num_draws {
+            let value = coin.draw_cube().unwrap();
+            for (count, coord) in msb_counts.iter_mut().zip(value.to_array()) {
+                *count += (coord.as_int() >> 63) as usize;
+            }
+            values.push(value);
+        }
+        for count in msb_counts {
+            assert!((400..600).contains(&count), "{}", count);
+        }
+
+        // all drawn values are distinct, and none of them is in the base field
+        for (i, value) in values.iter().enumerate() {
+            assert!(!values[i + 1..].contains(value));
+            assert!(!value.is_in_base_field());
+        }
+    }
+}
@@ -125,6 +125,67 @@ where
             let y = rand_value::<CubeExtension<B>>();
             bench.iter(|| black_box(x) * black_box(y))
         });
+
+        group.bench_function("cube/exp", |bench| {
+            let x = rand_value::<CubeExtension<B>>();
+            let y = rand_value::<B>().as_int();
+            bench.iter(|| black_box(x).exp(black_box(y)))
+        });
+
+        group.bench_function("cube/linear_combination", |bench| {
+            let values = rand_vector::<CubeExtension<B>>(100);
+            let scalars = rand_vector::<B>(100);
+            bench
+                .iter(|| CubeExtension::linear_combination(black_box(&values), black_box(&scalars)))
+        });
+
+        group.bench_function("cube/linear_combination_ext", |bench| {
+            let values = rand_vector::<CubeExtension<B>>(100);
+            let scalars = rand_vector::<B>(100)
+                .into_iter()
+                .map(CubeExtension::from)
+                .collect::<Vec<_>>();
+            bench.iter(|| {
+                CubeExtension::linear_combination_ext(black_box(&values), black_box(&scalars))
+            })
+        });
+
+        group.bench_function("cube/mul_base", |bench| {
+            let x = rand_value::<CubeExtension<B>>();
+            let y = rand_value::<B>();
+            bench.iter(|| black_box(x).mul_base(black_box(y)))
+        });
+
+        group.bench_function("cube/mul_base_slice", |bench| {
+            let y = rand_value::<B>();
+            bench.iter_batched(
+                || rand_vector::<CubeExtension<B>>(100),
+                |mut values| {
+                    CubeExtension::mul_base_slice(&mut values, black_box(y));
+                    values
+                },
+                BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_function("cube/inv", |bench| {
+            let x = rand_value::<CubeExtension<B>>();
+            bench.iter(|| black_box(x).inv())
+        });
+
+        // inversion with the second conjugate computed by applying frobenius twice; used as a
+        // baseline for the precomputed squared frobenius used by cube/inv
+        group.bench_function("cube/inv_reference", |bench| {
+            let x = rand_value::<CubeExtension<B>>();
+            bench.iter(|| {
+                let x = black_box(x);
+                let c1 = x.frobenius();
+                let c2 = c1.frobenius();
+                let numerator = c1 * c2;
+                let norm = (x * numerator).to_array()[0];
+                numerator.mul_base(norm.inv())
+            })
+        });
     }
 }
 
@@ -4,9 +4,13 @@
 // LICENSE file in the root directory of this source tree.
 
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
-use rand_utils::rand_vector;
+use rand_utils::{rand_value, rand_vector};
 use std::time::Duration;
-use winter_math::{fft, fields::f128::BaseElement, polynom, FieldElement};
+use winter_math::{
+    fft,
+    fields::{f128::BaseElement, f64, CubeExtension},
+    polynom, FieldElement,
+};
 
 const SIZES: [usize; 3] = [262_144, 524_288, 1_048_576];
 
@@ -38,5 +42,32 @@ fn syn_div(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(polynom_group, syn_div);
+fn eval_base_poly(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval_base_poly");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    for &size in SIZES.iter() {
+        let p: Vec<f64::BaseElement> = rand_vector(size);
+        let x = rand_value::<CubeExtension<f64::BaseElement>>();
+
+        group.bench_function(BenchmarkId::new("lifted", size), |bench| {
+            bench.iter(|| {
+                let lifted = p
+                    .iter()
+                    .map(|&c| CubeExtension::from(c))
+                    .collect::<Vec<_>>();
+                polynom::eval(&lifted, x)
+            });
+        });
+
+        group.bench_function(BenchmarkId::new("base", size), |bench| {
+            bench.iter(|| CubeExtension::eval_base_poly_at(&p, x));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(polynom_group, syn_div, eval_base_poly);
 criterion_main!(polynom_group);
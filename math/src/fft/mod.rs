@@ -12,7 +12,7 @@
 //! `n` is the domain size.
 
 use crate::{
-    field::{FieldElement, StarkField},
+    field::{CubeExtension, ExtensibleField, FieldElement, StarkField},
     utils::{get_power_series, log2},
 };
 
@@ -437,6 +437,96 @@ where
     serial::permute(values);
 }
 
+/// Evaluates a polynomial with coefficients in an extension field on all points of the specified
+/// domain using a single-threaded version of the FFT algorithm.
+///
+/// The coefficients of polynomial `p` are elements of field `E` (e.g., a
+/// [CubeExtension](crate::CubeExtension) of `B`), while the `twiddles` are elements of the base
+/// field `B`. Keeping twiddles in the base field makes the twiddle table smaller (e.g., three
+/// times smaller for a cubic extension) than it would be if the twiddles were lifted into `E`;
+/// the twiddles are applied via [FieldElement::mul_base()]. The evaluation is done in-place, and
+/// the results are written back into `p`.
+///
+/// The `twiddles` needed for evaluation can be obtained via `fft::get_twiddles()` function using
+/// `p.len()` as the domain size parameter. This implies that `twiddles.len()` must be equal to
+/// `p.len()` / 2.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `p` is not a power of two.
+/// * Length of `twiddles` is not `p.len()` / 2.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `p.len()`.
+pub fn eval_poly_with_base_twiddles<B, E>(p: &mut [E], twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert!(
+        p.len().is_power_of_two(),
+        "number of coefficients must be a power of 2"
+    );
+    assert_eq!(
+        p.len(),
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        p.len() / 2,
+        twiddles.len()
+    );
+    assert!(
+        log2(p.len()) <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        p.len()
+    );
+    serial::evaluate_poly(p, twiddles);
+}
+
+/// Evaluates a polynomial with coefficients in the cubic extension of `B` on all points of the
+/// specified domain using a single-threaded version of the FFT algorithm.
+///
+/// This is a specialization of [eval_poly_with_base_twiddles()] for the cubic extension; as for
+/// any extension field, twiddles are applied via [FieldElement::mul_base()], which for the cubic
+/// extension requires only three multiplications in the base field rather than a full extension
+/// field multiplication. The evaluation is done in-place, and the results are written back into
+/// `p`.
+///
+/// The `twiddles` needed for evaluation can be obtained via `fft::build_base_twiddles()`
+/// function using `p.len()` as the domain size parameter. This implies that `twiddles.len()`
+/// must be equal to `p.len()` / 2.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `p` is not a power of two.
+/// * Length of `twiddles` is not `p.len()` / 2.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `p.len()`.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{polynom, fft::*, get_power_series, log2};
+/// # use winter_math::{fields::{f64::BaseElement, CubeExtension}, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 1024;
+///
+/// // build a random polynomial with coefficients in the cubic extension field
+/// let mut p: Vec<CubeExtension<BaseElement>> = rand_vector(n);
+///
+/// // evaluate the polynomial over the domain using regular polynomial evaluation
+/// let g = CubeExtension::from(BaseElement::get_root_of_unity(log2(n)));
+/// let domain = get_power_series(g, n);
+/// let expected = polynom::eval_many(&p, &domain);
+///
+/// // evaluate the polynomial over the domain using FFT with base field twiddles
+/// let twiddles = build_base_twiddles::<BaseElement>(n);
+/// evaluate_poly_cube(&mut p, &twiddles);
+///
+/// assert_eq!(expected, p);
+/// ```
+pub fn evaluate_poly_cube<B>(p: &mut [CubeExtension<B>], twiddles: &[B])
+where
+    B: StarkField + ExtensibleField<3>,
+{
+    eval_poly_with_base_twiddles(p, twiddles);
+}
+
 // TWIDDLES
 // ================================================================================================
 
@@ -480,6 +570,25 @@ where
     twiddles
 }
 
+/// Returns a set of base field twiddles for evaluating polynomials with coefficients in an
+/// extension of `B` via [evaluate_poly_cube()] or [eval_poly_with_base_twiddles()].
+///
+/// The twiddles are kept in the base field, and thus, the table is smaller than a table of the
+/// same twiddles lifted into the extension field (e.g., three times smaller for the cubic
+/// extension). The returned twiddles are the same as the ones returned by [get_twiddles()], and
+/// the length of the returned vector is `domain_size` / 2.
+///
+/// # Panics
+/// Panics if:
+/// * `domain_size` is not a power of two.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `domain_size`.
+pub fn build_base_twiddles<B>(domain_size: usize) -> Vec<B>
+where
+    B: StarkField,
+{
+    get_twiddles(domain_size)
+}
+
 /// Returns a set of inverse twiddles for the specified domain size.
 ///
 /// These twiddles can then be used for FFT-based polynomial interpolation. The length of the
@@ -586,7 +695,7 @@ where
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn permute<E: FieldElement>(v: &mut [E]) {
+pub(crate) fn permute<E: FieldElement>(v: &mut [E]) {
     if cfg!(feature = "concurrent") && v.len() >= MIN_CONCURRENT_SIZE {
         #[cfg(feature = "concurrent")]
         concurrent::permute(v);
@@ -595,7 +704,7 @@ fn permute<E: FieldElement>(v: &mut [E]) {
     }
 }
 
-fn permute_index(size: usize, index: usize) -> usize {
+pub(crate) fn permute_index(size: usize, index: usize) -> usize {
     debug_assert!(index < size);
     if size == 1 {
         return 0;
@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use crate::{
-    field::{f128::BaseElement, StarkField},
+    field::{f128::BaseElement, f64, CubeExtension, StarkField},
     polynom,
     utils::{get_power_series, log2},
 };
@@ -69,6 +69,65 @@ fn fft_get_twiddles() {
     assert_eq!(expected, twiddles);
 }
 
+#[test]
+fn fft_cube_extension_with_base_twiddles() {
+    let n = super::MIN_CONCURRENT_SIZE * 2;
+    let p: Vec<CubeExtension<f64::BaseElement>> = rand_vector(n);
+    let twiddles = super::get_twiddles::<f64::BaseElement>(n);
+    let inv_twiddles = super::get_inv_twiddles::<f64::BaseElement>(n);
+
+    // evaluation with base field twiddles must match direct evaluation over the domain
+    let g = f64::BaseElement::get_root_of_unity(log2(n));
+    let domain = get_power_series(CubeExtension::from(g), n);
+    let mut evaluations = p.clone();
+    super::eval_poly_with_base_twiddles(&mut evaluations, &twiddles);
+    assert_eq!(polynom::eval_many(&p, &domain), evaluations);
+
+    // interpolating and then evaluating a random vector must yield the original vector
+    let values: Vec<CubeExtension<f64::BaseElement>> = rand_vector(n);
+    let mut result = values.clone();
+    super::interpolate_poly(&mut result, &inv_twiddles);
+    super::evaluate_poly(&mut result, &twiddles);
+    assert_eq!(values, result);
+
+    let mut result = values.clone();
+    super::interpolate_poly(&mut result, &inv_twiddles);
+    super::eval_poly_with_base_twiddles(&mut result, &twiddles);
+    assert_eq!(values, result);
+}
+
+#[test]
+fn fft_cube_extension_with_mul_base() {
+    let n = 1024;
+    let p: Vec<CubeExtension<f64::BaseElement>> = rand_vector(n);
+    let twiddles = super::build_base_twiddles::<f64::BaseElement>(n);
+    let inv_twiddles = super::get_inv_twiddles::<f64::BaseElement>(n);
+    assert_eq!(super::get_twiddles::<f64::BaseElement>(n), twiddles);
+
+    // evaluation must match direct evaluation over the domain, as well as the evaluation with
+    // twiddles lifted into the extension field
+    let g = f64::BaseElement::get_root_of_unity(log2(n));
+    let domain = get_power_series(CubeExtension::from(g), n);
+    let mut evaluations = p.clone();
+    super::evaluate_poly_cube(&mut evaluations, &twiddles);
+    assert_eq!(polynom::eval_many(&p, &domain), evaluations);
+
+    let mut expected = p.clone();
+    super::eval_poly_with_base_twiddles(&mut expected, &twiddles);
+    assert_eq!(expected, evaluations);
+
+    // interpolating the evaluations must yield the original polynomial
+    super::interpolate_poly(&mut evaluations, &inv_twiddles);
+    assert_eq!(p, evaluations);
+
+    // interpolating and then evaluating a random vector must yield the original vector
+    let values: Vec<CubeExtension<f64::BaseElement>> = rand_vector(n);
+    let mut result = values.clone();
+    super::interpolate_poly(&mut result, &inv_twiddles);
+    super::evaluate_poly_cube(&mut result, &twiddles);
+    assert_eq!(values, result);
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -3,19 +3,50 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{ExtensibleField, FieldElement};
+use super::{
+    ExtensibleField, ExtensionOf, FieldElement, MontgomeryField, QuadExtension, StarkField,
+};
+use crate::{batch_inversion, fft, polynom};
 use core::{
+    cmp::Ordering,
     convert::TryFrom,
     fmt,
+    hash::{Hash, Hasher},
+    iter::{self, Product, Sum},
+    marker::PhantomData,
+    mem,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     slice,
 };
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+use rand_utils::rand_value;
 use utils::{
-    collections::Vec, string::ToString, AsBytes, ByteReader, ByteWriter, Deserializable,
-    DeserializationError, Randomizable, Serializable, SliceReader,
+    collections::Vec,
+    string::{String, ToString},
+    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
+    Serializable, SliceReader,
 };
 
-// QUADRATIC EXTENSION FIELD
+// CONSTANTS
+// ================================================================================================
+
+/// Tag of compactly encoded elements which lie in the field being extended.
+const COMPACT_BASE_TAG: u8 = 0;
+
+/// Tag of compactly encoded elements which do not lie in the field being extended.
+const COMPACT_FULL_TAG: u8 = 1;
+
+/// Maximum number of random draws attempted by [CubeExtension::rand_nonzero()] and
+/// [CubeExtension::rand_nonzero_with_bytes()].
+const MAX_RAND_NONZERO_ATTEMPTS: usize = 1000;
+
+/// Error message for byte views requested on big-endian targets, where the in-memory encoding of
+/// elements is not the canonical little-endian one.
+const BIG_ENDIAN_BYTE_VIEW_ERROR: &str =
+    "byte views of cubic extension elements are not available on big-endian targets; \
+     use Serializable to encode elements instead";
+
+// CUBIC EXTENSION FIELD
 // ================================================================================================
 
 /// Represents an element in a cubic extension of a [StarkField](crate::StarkField).
@@ -23,6 +54,9 @@ use utils::{
 /// The extension element is defined as α + β * φ + γ * φ^2, where φ is a root of in irreducible
 /// polynomial defined by the implementation of the [ExtensibleField] trait, and α, β, γ are base
 /// field elements.
+///
+/// The field being extended can also be a [QuadExtension] of a StarkField; this yields a degree
+/// 6 extension of the StarkField built as a tower.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub struct CubeExtension<B: ExtensibleField<3>>(B, B, B);
@@ -33,351 +67,4679 @@ impl<B: ExtensibleField<3>> CubeExtension<B> {
         Self(a, b, c)
     }
 
-    /// Returns true if the base field specified by B type parameter supports cubic extensions.
-    pub fn is_supported() -> bool {
-        <B as ExtensibleField<3>>::is_supported()
+    /// Returns a new extension element instantiated from the provided array of base elements.
+    pub fn from_array(coeffs: [B; 3]) -> Self {
+        Self(coeffs[0], coeffs[1], coeffs[2])
     }
 
-    /// Converts a vector of base elements into a vector of elements in a cubic extension field
-    /// by fusing three adjacent base elements together. The output vector is half the length of
-    /// the source vector.
-    fn base_to_cubic_vector(source: Vec<B>) -> Vec<Self> {
-        debug_assert!(
-            source.len() % 3 == 0,
-            "source vector length must be divisible by three, but was {}",
-            source.len()
-        );
-        let mut v = core::mem::ManuallyDrop::new(source);
-        let p = v.as_mut_ptr();
-        let len = v.len() / 3;
-        let cap = v.capacity() / 3;
-        unsafe { Vec::from_raw_parts(p as *mut Self, len, cap) }
+    /// Returns a new extension element instantiated from the provided integers.
+    ///
+    /// Each integer is converted into a base element via `B::from(u64)`, and thus, values which
+    /// are greater than or equal to the modulus of the base field are silently reduced.
+    pub fn from_ints(a: u64, b: u64, c: u64) -> Self {
+        Self(B::from(a), B::from(b), B::from(c))
     }
-}
 
-impl<B: ExtensibleField<3>> FieldElement for CubeExtension<B> {
-    type PositiveInteger = B::PositiveInteger;
-    type BaseField = B;
+    /// Returns a vector of extension elements instantiated from the provided integer triples.
+    ///
+    /// Each triple is converted via [CubeExtension::from_ints()].
+    pub fn from_int_triples(triples: &[(u64, u64, u64)]) -> Vec<Self> {
+        triples
+            .iter()
+            .map(|&(a, b, c)| Self::from_ints(a, b, c))
+            .collect()
+    }
 
-    const ELEMENT_BYTES: usize = B::ELEMENT_BYTES * 3;
-    const IS_CANONICAL: bool = B::IS_CANONICAL;
-    const ZERO: Self = Self(B::ZERO, B::ZERO, B::ZERO);
-    const ONE: Self = Self(B::ONE, B::ZERO, B::ZERO);
+    /// Returns an array of base elements comprising this extension element.
+    pub fn to_array(self) -> [B; 3] {
+        [self.0, self.1, self.2]
+    }
 
+    /// Returns an element obtained by applying `f` to each coordinate of this element.
     #[inline]
-    fn inv(self) -> Self {
-        if self == Self::ZERO {
-            return self;
-        }
-
-        let x = [self.0, self.1, self.2];
-        let c1 = <B as ExtensibleField<3>>::frobenius(x);
-        let c2 = <B as ExtensibleField<3>>::frobenius(c1);
-        let numerator = <B as ExtensibleField<3>>::mul(c1, c2);
-
-        let norm = <B as ExtensibleField<3>>::mul(x, numerator);
-        debug_assert_eq!(norm[1], B::ZERO, "norm must be in the base field");
-        debug_assert_eq!(norm[2], B::ZERO, "norm must be in the base field");
-        let denom_inv = norm[0].inv();
+    #[must_use]
+    pub fn map_coords<F: Fn(B) -> B>(self, f: F) -> Self {
+        Self(f(self.0), f(self.1), f(self.2))
+    }
 
-        Self(
-            numerator[0] * denom_inv,
-            numerator[1] * denom_inv,
-            numerator[2] * denom_inv,
-        )
+    /// Returns the first coordinate (α) of this element.
+    #[inline]
+    pub fn a(&self) -> B {
+        self.0
     }
 
+    /// Returns the second coordinate (β) of this element.
     #[inline]
-    fn conjugate(&self) -> Self {
-        let result = <B as ExtensibleField<3>>::frobenius([self.0, self.1, self.2]);
-        Self(result[0], result[1], result[2])
+    pub fn b(&self) -> B {
+        self.1
     }
 
-    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
-        unsafe {
-            slice::from_raw_parts(
-                elements.as_ptr() as *const u8,
-                elements.len() * Self::ELEMENT_BYTES,
-            )
-        }
+    /// Returns the third coordinate (γ) of this element.
+    #[inline]
+    pub fn c(&self) -> B {
+        self.2
     }
 
-    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
-        if bytes.len() % Self::ELEMENT_BYTES != 0 {
-            return Err(DeserializationError::InvalidValue(format!(
-                "number of bytes ({}) does not divide into whole number of field elements",
-                bytes.len(),
-            )));
-        }
+    /// Returns true if the base field specified by B type parameter supports cubic extensions.
+    pub fn is_supported() -> bool {
+        <B as ExtensibleField<3>>::is_supported()
+    }
 
-        let p = bytes.as_ptr();
-        let len = bytes.len() / Self::ELEMENT_BYTES;
+    /// Returns a generator of the multiplicative group of this extension field.
+    ///
+    /// The generator is fixed for a given base field and is defined by
+    /// [ExtensibleField::GENERATOR_COEFFS]; its order is p^3 - 1, where p is the order of the base
+    /// field.
+    ///
+    /// # Panics
+    /// Panics if a generator is not defined for the cubic extension of the base field.
+    pub fn multiplicative_generator() -> Self {
+        let coeffs = <B as ExtensibleField<3>>::GENERATOR_COEFFS
+            .expect("multiplicative generator is not defined for this extension field");
+        let result = Self::from_array(coeffs);
+        // orders of base field elements divide p - 1, and thus, a generator cannot be among them
+        debug_assert!(
+            !result.is_in_base_field(),
+            "multiplicative generator cannot be an element of the base field"
+        );
+        result
+    }
 
-        // make sure the bytes are aligned on the boundary consistent with base element alignment
-        if (p as usize) % Self::BaseField::ELEMENT_BYTES != 0 {
-            return Err(DeserializationError::InvalidValue(
-                "slice memory alignment is not valid for this field element type".to_string(),
-            ));
-        }
+    /// Returns true if this element is ZERO.
+    ///
+    /// Coordinates are checked in order, and the check stops as soon as a coordinate which is not
+    /// ZERO is encountered.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0 == B::ZERO && self.1 == B::ZERO && self.2 == B::ZERO
+    }
 
-        Ok(slice::from_raw_parts(p as *const Self, len))
+    /// Returns true if this element is ONE.
+    ///
+    /// Coordinates are checked in order, and the check stops as soon as a coordinate which does
+    /// not match ONE is encountered.
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.0 == B::ONE && self.1 == B::ZERO && self.2 == B::ZERO
     }
 
-    fn zeroed_vector(n: usize) -> Vec<Self> {
-        // get three times the number of base elements and re-interpret them as cubic field
-        // elements
-        let result = B::zeroed_vector(n * 3);
-        Self::base_to_cubic_vector(result)
+    /// Returns true if this element lies in the field being extended; i.e., if both β and γ
+    /// coordinates of this element are ZERO.
+    #[inline]
+    pub fn is_in_base_field(&self) -> bool {
+        self.1 == B::ZERO && self.2 == B::ZERO
     }
 
-    fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
-        let ptr = elements.as_ptr();
-        let len = elements.len() * 3;
-        unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
+    /// Returns the α coordinate of this element if the element lies in the field being extended,
+    /// or None otherwise.
+    #[inline]
+    pub fn to_base(&self) -> Option<B> {
+        if self.is_in_base_field() {
+            Some(self.0)
+        } else {
+            None
+        }
     }
-}
 
-impl<B: ExtensibleField<3>> Randomizable for CubeExtension<B> {
-    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+    /// Returns this element added to itself three times.
+    ///
+    /// The computation is performed coordinate-wise using doubling in the base field.
+    #[inline]
+    #[must_use]
+    pub fn triple(self) -> Self {
+        Self(
+            self.0.double() + self.0,
+            self.1.double() + self.1,
+            self.2.double() + self.2,
+        )
+    }
 
-    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::try_from(bytes).ok()
+    /// Returns the product of this element and the base field element `rhs`.
+    ///
+    /// The multiplication is performed via [ExtensibleField::mul_base()]; by default, each
+    /// coordinate is multiplied by `rhs` directly, which requires only three multiplications in
+    /// the base field, rather than a full multiplication in the extension field.
+    #[inline]
+    #[must_use]
+    pub fn mul_base(self, rhs: B) -> Self {
+        Self::from_array(<B as ExtensibleField<3>>::mul_base(self.to_array(), rhs))
     }
-}
 
-impl<B: ExtensibleField<3>> fmt::Display for CubeExtension<B> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {}, {})", self.0, self.1, self.2)
+    /// Returns the sum of this element and the base field element `rhs`.
+    ///
+    /// Only the first coordinate is affected, and thus, `rhs` does not need to be lifted into
+    /// the extension field first.
+    #[inline]
+    #[must_use]
+    pub fn add_base(self, rhs: B) -> Self {
+        Self(self.0 + rhs, self.1, self.2)
     }
-}
 
-// OVERLOADED OPERATORS
-// ------------------------------------------------------------------------------------------------
+    /// Returns the difference between this element and the base field element `rhs`.
+    ///
+    /// Only the first coordinate is affected, and thus, `rhs` does not need to be lifted into
+    /// the extension field first.
+    #[inline]
+    #[must_use]
+    pub fn sub_base(self, rhs: B) -> Self {
+        Self(self.0 - rhs, self.1, self.2)
+    }
 
-impl<B: ExtensibleField<3>> Add for CubeExtension<B> {
-    type Output = Self;
+    /// Returns `minuend` - self.
+    ///
+    /// This is useful in accumulation loops where the element being subtracted is the result of
+    /// a chained computation, e.g., `acc.mul_base(x).sub_from(c)`.
+    #[inline]
+    #[must_use]
+    pub fn sub_from(self, minuend: Self) -> Self {
+        minuend - self
+    }
 
+    /// Negates this element in place.
     #[inline]
-    fn add(self, rhs: Self) -> Self {
-        Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    pub fn negate_assign(&mut self) {
+        self.0 = -self.0;
+        self.1 = -self.1;
+        self.2 = -self.2;
     }
-}
 
-impl<B: ExtensibleField<3>> AddAssign for CubeExtension<B> {
+    /// Negates this element in place.
+    ///
+    /// This is the same as [negate_assign()](CubeExtension::negate_assign).
     #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs
+    pub fn neg_assign(&mut self) {
+        self.negate_assign()
     }
-}
 
-impl<B: ExtensibleField<3>> Sub for CubeExtension<B> {
-    type Output = Self;
+    /// Replaces this element with its multiplicative inverse in place; ZERO is left unchanged
+    /// (this matches the behavior of [FieldElement::inv()]).
+    pub fn invert_assign(&mut self) {
+        if self.is_zero() {
+            return;
+        }
 
-    #[inline]
-    fn sub(self, rhs: Self) -> Self {
-        Self(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+        let (numerator, norm) = self.norm_with_numerator();
+        let denom_inv = norm.inv();
+        self.0 = numerator[0] * denom_inv;
+        self.1 = numerator[1] * denom_inv;
+        self.2 = numerator[2] * denom_inv;
     }
-}
 
-impl<B: ExtensibleField<3>> SubAssign for CubeExtension<B> {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+    /// Returns an infinite iterator over successive powers of this element: [1, x, x^2, ...].
+    ///
+    /// Each power is computed from the previous one using a single multiplication.
+    pub fn powers(self) -> impl Iterator<Item = Self> {
+        iter::successors(Some(Self(B::ONE, B::ZERO, B::ZERO)), move |&p| {
+            Some(p * self)
+        })
     }
-}
 
-impl<B: ExtensibleField<3>> Mul for CubeExtension<B> {
-    type Output = Self;
+    /// Returns a vector containing the first `n` powers of this element: [1, x, ..., x^(n-1)].
+    ///
+    /// For large `n`, [get_power_series()](crate::get_power_series) may be faster as it can
+    /// generate the powers in multiple threads when `concurrent` feature is enabled.
+    pub fn powers_n(self, n: usize) -> Vec<Self> {
+        self.powers().take(n).collect()
+    }
 
-    #[inline]
-    fn mul(self, rhs: Self) -> Self {
-        let result =
-            <B as ExtensibleField<3>>::mul([self.0, self.1, self.2], [rhs.0, rhs.1, rhs.2]);
+    /// Returns the result of applying the Frobenius automorphism to this element.
+    ///
+    /// For an element x, this computes x^q, where q is the number of elements in the field being
+    /// extended (i.e., the modulus of the base field when B is a StarkField).
+    pub fn frobenius(&self) -> Self {
+        let result = <B as ExtensibleField<3>>::frobenius([self.0, self.1, self.2]);
         Self(result[0], result[1], result[2])
     }
-}
 
-impl<B: ExtensibleField<3>> MulAssign for CubeExtension<B> {
-    #[inline]
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = *self * rhs
+    /// Returns the result of applying the Frobenius automorphism to this element `k` times.
+    ///
+    /// Since the automorphism has order 3, this is computed in a single step as either this
+    /// element, its [frobenius](CubeExtension::frobenius()), or its second conjugate, depending on
+    /// `k` mod 3.
+    pub fn frobenius_k(&self, k: usize) -> Self {
+        Self::from_array(<B as ExtensibleField<3>>::frobenius_k(self.to_array(), k))
     }
-}
 
-impl<B: ExtensibleField<3>> Div for CubeExtension<B> {
-    type Output = Self;
+    /// Returns this element together with its two non-trivial Galois conjugates: [x, x^q, x^(q^2)],
+    /// where q is the number of elements in the field being extended.
+    ///
+    /// The product of the conjugates is the [norm](CubeExtension::norm()) of this element, and
+    /// their sum is its [trace](CubeExtension::trace()).
+    pub fn conjugates(&self) -> [Self; 3] {
+        let x = [self.0, self.1, self.2];
+        let c1 = <B as ExtensibleField<3>>::frobenius(x);
+        let c2 = <B as ExtensibleField<3>>::frobenius_squared(x);
+        [*self, Self(c1[0], c1[1], c1[2]), Self(c2[0], c2[1], c2[2])]
+    }
 
-    #[inline]
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    fn div(self, rhs: Self) -> Self {
-        self * rhs.inv()
+    /// Returns the norm of this element relative to the base field.
+    ///
+    /// The norm is computed as a product of this element and its two Galois conjugates, and thus
+    /// always lies in the base field.
+    pub fn norm(&self) -> B {
+        self.norm_with_numerator().1
     }
-}
 
-impl<B: ExtensibleField<3>> DivAssign for CubeExtension<B> {
-    #[inline]
-    fn div_assign(&mut self, rhs: Self) {
-        *self = *self / rhs
+    /// Returns the trace of this element relative to the base field.
+    ///
+    /// The trace is computed as a sum of this element and its two Galois conjugates, and thus
+    /// always lies in the base field.
+    pub fn trace(&self) -> B {
+        let [c0, c1, c2] = self.conjugates();
+        let trace = c0 + c1 + c2;
+        debug_assert_eq!(trace.1, B::ZERO, "trace must be in the base field");
+        debug_assert_eq!(trace.2, B::ZERO, "trace must be in the base field");
+        trace.0
     }
-}
 
-impl<B: ExtensibleField<3>> Neg for CubeExtension<B> {
-    type Output = Self;
+    /// Returns a vector of pointwise sums of elements in slices `a` and `b`.
+    ///
+    /// The sums are computed over the flattened coefficient representation of the elements via
+    /// [ExtensibleField::add_coeffs()], and thus, may be vectorized for some base fields.
+    ///
+    /// # Panics
+    /// Panics if lengths of `a` and `b` slices are not the same.
+    pub fn add_slices(a: &[Self], b: &[Self]) -> Vec<Self> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "number of values must be the same for both slices"
+        );
+        let mut result = vec![Self(B::ZERO, B::ZERO, B::ZERO); a.len()];
+        <B as ExtensibleField<3>>::add_coeffs(
+            Self::as_coeffs(a),
+            Self::as_coeffs(b),
+            Self::as_coeffs_mut(&mut result),
+        );
+        result
+    }
 
-    #[inline]
-    fn neg(self) -> Self {
-        Self(-self.0, -self.1, -self.2)
+    /// Returns a vector of pointwise differences of elements in slices `a` and `b`.
+    ///
+    /// The differences are computed over the flattened coefficient representation of the
+    /// elements via [ExtensibleField::sub_coeffs()], and thus, may be vectorized for some base
+    /// fields.
+    ///
+    /// # Panics
+    /// Panics if lengths of `a` and `b` slices are not the same.
+    pub fn sub_slices(a: &[Self], b: &[Self]) -> Vec<Self> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "number of values must be the same for both slices"
+        );
+        let mut result = vec![Self(B::ZERO, B::ZERO, B::ZERO); a.len()];
+        <B as ExtensibleField<3>>::sub_coeffs(
+            Self::as_coeffs(a),
+            Self::as_coeffs(b),
+            Self::as_coeffs_mut(&mut result),
+        );
+        result
     }
-}
 
-// TYPE CONVERSIONS
-// ------------------------------------------------------------------------------------------------
+    /// Returns a vector of pointwise products of elements in slices `a` and `b`.
+    ///
+    /// # Panics
+    /// Panics if lengths of `a` and `b` slices are not the same.
+    pub fn mul_slices(a: &[Self], b: &[Self]) -> Vec<Self> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "number of values must be the same for both slices"
+        );
+        a.iter().zip(b).map(|(&a, &b)| a * b).collect()
+    }
 
-impl<B: ExtensibleField<3>> From<B> for CubeExtension<B> {
-    fn from(value: B) -> Self {
-        Self(value, B::ZERO, B::ZERO)
+    /// Multiplies each element in slice `a` by the element at the same position in slice `b`,
+    /// and saves the result into `a`.
+    ///
+    /// # Panics
+    /// Panics if lengths of `a` and `b` slices are not the same.
+    pub fn mul_assign_slice(a: &mut [Self], b: &[Self]) {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "number of values must be the same for both slices"
+        );
+        a.iter_mut().zip(b).for_each(|(a, &b)| *a *= b);
     }
-}
 
-impl<B: ExtensibleField<3>> From<u128> for CubeExtension<B> {
-    fn from(value: u128) -> Self {
-        Self(B::from(value), B::ZERO, B::ZERO)
+    /// Multiplies all elements of the provided slice by the base field element `scalar` in place.
+    ///
+    /// This is the same as calling [CubeExtension::mul_base()] for every element, but the
+    /// multiplication is performed over the flattened coefficients of the elements, and thus,
+    /// base fields may use vectorized instructions for it (e.g., AVX2 for the 64-bit field).
+    pub fn mul_base_slice(values: &mut [Self], scalar: B) {
+        <B as ExtensibleField<3>>::scale_coeffs(Self::as_coeffs_mut(values), scalar);
     }
-}
 
-impl<B: ExtensibleField<3>> From<u64> for CubeExtension<B> {
-    fn from(value: u64) -> Self {
-        Self(B::from(value), B::ZERO, B::ZERO)
+    /// Negates all elements of the provided slice in place.
+    ///
+    /// The elements are negated via their flattened coefficient representation, and thus, no
+    /// memory is allocated.
+    pub fn negate_slice(values: &mut [Self]) {
+        for value in Self::as_coeffs_mut(values) {
+            *value = -*value;
+        }
     }
-}
 
-impl<B: ExtensibleField<3>> From<u32> for CubeExtension<B> {
-    fn from(value: u32) -> Self {
-        Self(B::from(value), B::ZERO, B::ZERO)
+    /// Returns sum of `values[i]` * `scalars[i]` for all i, where the scalars are base field
+    /// elements.
+    ///
+    /// Each term is computed using [CubeExtension::mul_base()], which requires only three
+    /// multiplications in the base field; this is much faster than lifting the scalars into the
+    /// extension field and calling [CubeExtension::linear_combination_ext()].
+    ///
+    /// # Panics
+    /// Panics if lengths of `values` and `scalars` slices are not the same.
+    pub fn linear_combination(values: &[Self], scalars: &[B]) -> Self {
+        assert_eq!(
+            values.len(),
+            scalars.len(),
+            "number of values must be the same for both slices"
+        );
+        values
+            .iter()
+            .zip(scalars)
+            .fold(Self(B::ZERO, B::ZERO, B::ZERO), |acc, (&value, &scalar)| {
+                acc + value.mul_base(scalar)
+            })
     }
-}
 
-impl<B: ExtensibleField<3>> From<u16> for CubeExtension<B> {
-    fn from(value: u16) -> Self {
-        Self(B::from(value), B::ZERO, B::ZERO)
+    /// Returns sum of `values[i]` * `scalars[i]` for all i, where the scalars are elements in
+    /// the extension field.
+    ///
+    /// # Panics
+    /// Panics if lengths of `values` and `scalars` slices are not the same.
+    pub fn linear_combination_ext(values: &[Self], scalars: &[Self]) -> Self {
+        assert_eq!(
+            values.len(),
+            scalars.len(),
+            "number of values must be the same for both slices"
+        );
+        values
+            .iter()
+            .zip(scalars)
+            .fold(Self(B::ZERO, B::ZERO, B::ZERO), |acc, (&value, &scalar)| {
+                acc + value * scalar
+            })
     }
-}
 
-impl<B: ExtensibleField<3>> From<u8> for CubeExtension<B> {
-    fn from(value: u8) -> Self {
-        Self(B::from(value), B::ZERO, B::ZERO)
+    /// Returns the inner product of slices `a` and `b`: sum of `a[i]` * `b[i]` for all i.
+    ///
+    /// This is the same as [CubeExtension::linear_combination_ext()], and is provided under the
+    /// name commonly used for random linear combinations in protocols.
+    ///
+    /// # Panics
+    /// Panics if lengths of `a` and `b` slices are not the same.
+    pub fn inner_product(a: &[Self], b: &[Self]) -> Self {
+        Self::linear_combination_ext(a, b)
     }
-}
 
-impl<'a, B: ExtensibleField<3>> TryFrom<&'a [u8]> for CubeExtension<B> {
-    type Error = DeserializationError;
+    /// Returns the inner product of slice `a` of extension elements and slice `b` of base field
+    /// elements.
+    ///
+    /// This is the same as [CubeExtension::linear_combination()], and thus, each term requires
+    /// only three multiplications in the base field.
+    ///
+    /// # Panics
+    /// Panics if lengths of `a` and `b` slices are not the same.
+    pub fn inner_product_base(a: &[Self], b: &[B]) -> Self {
+        Self::linear_combination(a, b)
+    }
 
-    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
-    /// is not a valid field element. The bytes are assumed to be in little-endian byte order.
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        if bytes.len() < Self::ELEMENT_BYTES {
+    /// Evaluates a polynomial with coefficients in the base field at the specified point in the
+    /// extension field.
+    ///
+    /// The evaluation is performed using Horner's method; at each step, the accumulator is
+    /// multiplied by `x` and the next coefficient is added to the first coordinate of the
+    /// accumulator only. This avoids lifting every coefficient into the extension field, as
+    /// [polynom::eval()](crate::polynom::eval) does.
+    pub fn eval_base_poly_at(coeffs: &[B], x: Self) -> Self {
+        coeffs
+            .iter()
+            .rev()
+            .fold(Self(B::ZERO, B::ZERO, B::ZERO), |acc, &coeff| {
+                let mut result = acc * x;
+                result.0 += coeff;
+                result
+            })
+    }
+
+    /// Evaluates a polynomial with coefficients in the extension field at all of the specified
+    /// points and returns a vector of results.
+    ///
+    /// Each point is evaluated independently using Horner's method, and thus, the complexity of
+    /// this function is O(`n` * `m`), where `n` is the number of coefficients and `m` is the
+    /// number of points. An empty set of coefficients evaluates to ZERO at every point.
+    pub fn eval_many(coeffs: &[Self], points: &[Self]) -> Vec<Self> {
+        points
+            .iter()
+            .map(|&x| {
+                coeffs
+                    .iter()
+                    .rev()
+                    .fold(Self(B::ZERO, B::ZERO, B::ZERO), |acc, &coeff| {
+                        acc * x + coeff
+                    })
+            })
+            .collect()
+    }
+
+    /// Divides a polynomial with coefficients in the extension field by (x - `z`) and returns the
+    /// coefficients of the quotient.
+    ///
+    /// The division is performed using synthetic division, and `z` is expected to be a root of
+    /// the polynomial (e.g., as in computing (f(x) - f(z)) / (x - z) for DEEP composition). Any
+    /// non-zero remainder is discarded in release builds. The quotient has one fewer coefficient
+    /// than the input; thus, dividing a constant (or an empty) polynomial yields an empty vector.
+    ///
+    /// # Panics
+    /// In debug builds, panics if `z` is not a root of the polynomial.
+    pub fn divide_by_linear(coeffs: &[Self], z: Self) -> Vec<Self> {
+        if coeffs.is_empty() {
+            return Vec::new();
+        }
+
+        let mut quotient = vec![Self(B::ZERO, B::ZERO, B::ZERO); coeffs.len() - 1];
+        let mut acc = Self(B::ZERO, B::ZERO, B::ZERO);
+        for i in (1..coeffs.len()).rev() {
+            acc = acc * z + coeffs[i];
+            quotient[i - 1] = acc;
+        }
+
+        let remainder = acc * z + coeffs[0];
+        debug_assert!(
+            remainder.is_zero(),
+            "polynomial is not divisible by (x - z): remainder is not zero"
+        );
+        quotient
+    }
+
+    /// Converts a vector of base elements into a vector of elements in a cubic extension field
+    /// by fusing three adjacent base elements together. The output vector is one third the length
+    /// of the source vector.
+    ///
+    /// When the capacity of the source vector is divisible by three, the underlying memory is
+    /// re-interpreted without copying; otherwise, the elements are copied into a new vector.
+    ///
+    /// # Errors
+    /// Returns an error if the length of the source vector is not divisible by three.
+    pub fn from_base_vector(source: Vec<B>) -> Result<Vec<Self>, DeserializationError> {
+        if source.len() % 3 != 0 {
             return Err(DeserializationError::InvalidValue(format!(
-                "not enough bytes for a full field element; expected {} bytes, but was {} bytes",
-                Self::ELEMENT_BYTES,
-                bytes.len(),
+                "number of base elements must be divisible by three, but was {}",
+                source.len()
             )));
         }
-        if bytes.len() > Self::ELEMENT_BYTES {
+
+        if source.capacity() % 3 != 0 {
+            let result = source
+                .chunks_exact(3)
+                .map(|c| Self(c[0], c[1], c[2]))
+                .collect();
+            return Ok(result);
+        }
+
+        Ok(Self::base_to_cubic_vector(source))
+    }
+
+    /// Builds a vector of elements in a cubic extension field by pulling base elements from the
+    /// provided iterator in groups of three; each group is fused into a single extension element.
+    ///
+    /// This is useful when base elements are produced lazily (e.g., while streaming them from a
+    /// reader), and collecting them into a vector first would require an extra copy.
+    ///
+    /// # Errors
+    /// Returns an error if the total number of elements yielded by the iterator is not divisible
+    /// by three.
+    pub fn from_base_iter<I: Iterator<Item = B>>(
+        mut iter: I,
+    ) -> Result<Vec<Self>, DeserializationError> {
+        let mut result = Vec::with_capacity(iter.size_hint().0 / 3);
+        while let Some(a) = iter.next() {
+            match (iter.next(), iter.next()) {
+                (Some(b), Some(c)) => result.push(Self(a, b, c)),
+                (b, _) => {
+                    let num_elements = result.len() * 3 + if b.is_some() { 2 } else { 1 };
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "number of base elements must be divisible by three, but was {}",
+                        num_elements
+                    )));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Converts a slice of base elements into a vector of elements in a cubic extension field by
+    /// grouping three adjacent base elements together; if the length of the source slice is not
+    /// divisible by three, the last group is padded with ZEROs.
+    ///
+    /// Unlike [CubeExtension::from_base_vector()], this function always copies the elements.
+    pub fn from_base_padded(source: &[B]) -> Vec<Self> {
+        source
+            .chunks(3)
+            .map(|c| {
+                Self(
+                    c[0],
+                    c.get(1).copied().unwrap_or(B::ZERO),
+                    c.get(2).copied().unwrap_or(B::ZERO),
+                )
+            })
+            .collect()
+    }
+
+    /// Re-interprets a slice of base elements as a slice of elements in a cubic extension field
+    /// by fusing three adjacent base elements together. No data is copied.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The length of the source slice is not divisible by three.
+    /// * The memory of the source slice is not aligned on the boundary required by the extension
+    ///   field element type.
+    pub fn from_base_slice(source: &[B]) -> Result<&[Self], DeserializationError> {
+        if source.len() % 3 != 0 {
             return Err(DeserializationError::InvalidValue(format!(
-                "too many bytes for a field element; expected {} bytes, but was {} bytes",
-                Self::ELEMENT_BYTES,
-                bytes.len(),
+                "number of base elements ({}) does not divide into whole number of field elements",
+                source.len(),
             )));
         }
-        let mut reader = SliceReader::new(bytes);
-        Self::read_from(&mut reader)
+
+        let p = source.as_ptr();
+        let len = source.len() / 3;
+
+        // the extension element is laid out as three consecutive base elements; thus, its
+        // alignment is expected to be the same as for the base elements, but we check anyway
+        if (p as usize) % mem::align_of::<Self>() != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+
+        assert_coeff_layout::<B>();
+        Ok(unsafe { slice::from_raw_parts(p as *const Self, len) })
     }
-}
 
-impl<B: ExtensibleField<3>> AsBytes for CubeExtension<B> {
-    fn as_bytes(&self) -> &[u8] {
-        // TODO: take endianness into account
-        let self_ptr: *const Self = self;
-        unsafe { slice::from_raw_parts(self_ptr as *const u8, Self::ELEMENT_BYTES) }
+    /// Converts a vector of cubic extension elements into a vector of their coefficients in B,
+    /// placing the three coefficients of each element next to each other.
+    ///
+    /// The underlying memory is re-interpreted without copying: the length and the capacity of
+    /// the returned vector are three times the length and the capacity of the source vector. This
+    /// is the inverse of [CubeExtension::from_base_vector()].
+    pub fn into_base_vec(elements: Vec<Self>) -> Vec<B> {
+        assert_coeff_layout::<B>();
+        // the source vector must not be dropped, since its memory is now owned by the result
+        let mut v = core::mem::ManuallyDrop::new(elements);
+        let p = v.as_mut_ptr();
+        let len = v.len() * 3;
+        let cap = v.capacity() * 3;
+        unsafe { Vec::from_raw_parts(p as *mut B, len, cap) }
     }
-}
 
-// SERIALIZATION / DESERIALIZATION
-// ------------------------------------------------------------------------------------------------
+    /// Converts a vector of cubic extension elements into a vector of arrays of their
+    /// coefficients in B.
+    ///
+    /// An extension element has the same memory layout as an array of three base elements, and
+    /// thus, the underlying memory is re-interpreted without copying; the length and the capacity
+    /// of the returned vector are the same as those of the source vector.
+    pub fn into_array_vec(elements: Vec<Self>) -> Vec<[B; 3]> {
+        assert_coeff_layout::<B>();
+        // the source vector must not be dropped, since its memory is now owned by the result
+        let mut v = core::mem::ManuallyDrop::new(elements);
+        let p = v.as_mut_ptr();
+        let len = v.len();
+        let cap = v.capacity();
+        unsafe { Vec::from_raw_parts(p as *mut [B; 3], len, cap) }
+    }
 
-impl<B: ExtensibleField<3>> Serializable for CubeExtension<B> {
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        self.0.write_into(target);
-        self.1.write_into(target);
-        self.2.write_into(target);
+    /// Converts a vector of coefficient arrays into a vector of cubic extension elements; this is
+    /// the inverse of [CubeExtension::into_array_vec()].
+    ///
+    /// The underlying memory is re-interpreted without copying; the length and the capacity of
+    /// the returned vector are the same as those of the source vector.
+    pub fn from_array_vec(arrays: Vec<[B; 3]>) -> Vec<Self> {
+        assert_coeff_layout::<B>();
+        // the source vector must not be dropped, since its memory is now owned by the result
+        let mut v = core::mem::ManuallyDrop::new(arrays);
+        let p = v.as_mut_ptr();
+        let len = v.len();
+        let cap = v.capacity();
+        unsafe { Vec::from_raw_parts(p as *mut Self, len, cap) }
+    }
+
+    /// Returns a vector of coefficients in B of the provided cubic extension elements; the three
+    /// coefficients of each element are placed next to each other.
+    ///
+    /// Unlike [CubeExtension::into_base_vec()], this copies the coefficients.
+    pub fn to_base_vec(elements: &[Self]) -> Vec<B> {
+        Self::as_coeffs(elements).to_vec()
+    }
+
+    /// Splits the provided elements into three vectors holding α, β, and γ coordinates of the
+    /// elements respectively.
+    ///
+    /// Unlike [CubeExtension::to_base_vec()], which keeps the coordinates of each element next
+    /// to each other, this groups the coordinates by their position.
+    pub fn deinterleave(elements: &[Self]) -> (Vec<B>, Vec<B>, Vec<B>) {
+        let mut a = Vec::with_capacity(elements.len());
+        let mut b = Vec::with_capacity(elements.len());
+        let mut c = Vec::with_capacity(elements.len());
+        for element in elements {
+            a.push(element.0);
+            b.push(element.1);
+            c.push(element.2);
+        }
+        (a, b, c)
+    }
+
+    /// Returns a vector of extension elements with α, β, and γ coordinates taken from slices
+    /// `a`, `b`, and `c` respectively; this is the inverse of [CubeExtension::deinterleave()].
+    ///
+    /// # Panics
+    /// Panics if lengths of `a`, `b`, and `c` slices are not the same.
+    pub fn interleave(a: &[B], b: &[B], c: &[B]) -> Vec<Self> {
+        assert!(
+            a.len() == b.len() && a.len() == c.len(),
+            "number of values must be the same for all slices"
+        );
+        a.iter()
+            .zip(b)
+            .zip(c)
+            .map(|((&a, &b), &c)| Self(a, b, c))
+            .collect()
+    }
+
+    /// Converts a table of extension field columns into a table of base field columns.
+    ///
+    /// Each extension column is split into three base columns holding α, β, and γ coordinates of
+    /// the column's elements respectively. Thus, column i of the source table becomes columns
+    /// 3 * i, 3 * i + 1, and 3 * i + 2 of the result; the number of rows does not change.
+    pub fn flatten_columns(table: &[Vec<Self>]) -> Vec<Vec<B>> {
+        let mut result = Vec::with_capacity(table.len() * 3);
+        for column in table {
+            let (a, b, c) = Self::deinterleave(column);
+            result.push(a);
+            result.push(b);
+            result.push(c);
+        }
+        result
+    }
+
+    /// Converts a table of base field columns into a table of extension field columns; this is
+    /// the inverse of [CubeExtension::flatten_columns()].
+    ///
+    /// Every three consecutive base columns are fused into a single extension column, with the
+    /// columns holding α, β, and γ coordinates of the column's elements respectively.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The number of base columns is not divisible by three.
+    /// * Any three base columns fused together do not have the same length.
+    pub fn unflatten_columns(table: &[Vec<B>]) -> Vec<Vec<Self>> {
+        assert!(
+            table.len() % 3 == 0,
+            "number of base columns must be divisible by three, but was {}",
+            table.len()
+        );
+        table
+            .chunks_exact(3)
+            .map(|columns| {
+                assert!(
+                    columns[0].len() == columns[1].len() && columns[0].len() == columns[2].len(),
+                    "base columns fused into an extension column must have the same length"
+                );
+                Self::interleave(&columns[0], &columns[1], &columns[2])
+            })
+            .collect()
+    }
+
+    /// Serializes this element into the `target` using a compact encoding.
+    ///
+    /// The encoding starts with a one-byte tag: if both β and γ coordinates of this element are
+    /// ZERO, the tag is 0 and is followed by the serialized α coordinate only; otherwise, the tag
+    /// is 1 and is followed by all three serialized coordinates.
+    ///
+    /// This encoding is not compatible with the fixed-width encoding used by the [Serializable]
+    /// implementation; elements written using this method must be read using
+    /// [CubeExtension::read_from_compact()].
+    pub fn write_into_compact<W: ByteWriter>(&self, target: &mut W) {
+        if self.is_in_base_field() {
+            target.write_u8(COMPACT_BASE_TAG);
+            self.0.write_into(target);
+        } else {
+            target.write_u8(COMPACT_FULL_TAG);
+            self.write_into(target);
+        }
+    }
+
+    /// Reads an element encoded using [CubeExtension::write_into_compact()] from the `source`.
+    ///
+    /// # Errors
+    /// Returns an error if the tag is invalid, or if the coordinates could not be read from the
+    /// `source`.
+    pub fn read_from_compact<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            COMPACT_BASE_TAG => Ok(Self(B::read_from(source)?, B::ZERO, B::ZERO)),
+            COMPACT_FULL_TAG => Self::read_from(source),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "invalid compact encoding tag: {}",
+                tag
+            ))),
+        }
+    }
+
+    /// Serializes the provided elements into the `target` prefixed with the number of elements.
+    ///
+    /// The number of elements is encoded as an unsigned LEB128 varint, and is followed by the
+    /// elements serialized using the [Serializable] implementation. Elements written using this
+    /// method must be read using [CubeExtension::read_vec_from()].
+    pub fn write_vec_into<W: ByteWriter>(elements: &[Self], target: &mut W) {
+        write_varint(elements.len() as u64, target);
+        Self::write_batch_into(elements, target);
+    }
+
+    /// Reads a vector of elements written using [CubeExtension::write_vec_into()] from the
+    /// `source`.
+    ///
+    /// [ByteReader] does not expose the number of remaining bytes, and thus, the number of
+    /// elements is validated as the elements are read: memory for the result is not allocated
+    /// upfront, so a malformed length prefix fails as soon as the `source` runs out of bytes.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The length prefix is not a valid varint or does not fit into `usize`.
+    /// * The `source` does not contain enough bytes for the specified number of elements.
+    /// * Any of the elements could not be read from the `source`.
+    pub fn read_vec_from<R: ByteReader>(source: &mut R) -> Result<Vec<Self>, DeserializationError> {
+        let num_elements = read_varint(source)?;
+        let num_elements = usize::try_from(num_elements).map_err(|_| {
+            DeserializationError::InvalidValue(format!(
+                "number of elements {} does not fit into usize",
+                num_elements
+            ))
+        })?;
+        Self::read_batch_from(source, num_elements)
+    }
+
+    /// Computes the norm of this element together with the product of its two Galois conjugates.
+    ///
+    /// The product of the conjugates is the numerator of the inverse of this element (i.e., the
+    /// inverse is obtained by dividing it by the norm).
+    #[inline]
+    fn norm_with_numerator(&self) -> ([B; 3], B) {
+        let x = [self.0, self.1, self.2];
+        // the second conjugate is computed directly from x so that base fields which provide
+        // precomputed coefficients of the squared automorphism don't need to wait for c1
+        let c1 = <B as ExtensibleField<3>>::frobenius(x);
+        let c2 = <B as ExtensibleField<3>>::frobenius_squared(x);
+        let numerator = <B as ExtensibleField<3>>::mul(c1, c2);
+
+        let norm = <B as ExtensibleField<3>>::mul(x, numerator);
+        debug_assert_eq!(norm[1], B::ZERO, "norm must be in the base field");
+        debug_assert_eq!(norm[2], B::ZERO, "norm must be in the base field");
+        (numerator, norm[0])
+    }
+
+    /// Re-interprets a slice of cubic extension elements as a slice of their coefficients in B.
+    ///
+    /// This is safe because the struct is `#[repr(C)]` and consists of exactly three values of B
+    /// without padding; the latter is checked at compile time via [CoeffLayout].
+    fn as_coeffs(elements: &[Self]) -> &[B] {
+        assert_coeff_layout::<B>();
+        let ptr = elements.as_ptr();
+        let len = elements.len() * 3;
+        unsafe { slice::from_raw_parts(ptr as *const B, len) }
+    }
+
+    /// Same as [CubeExtension::as_coeffs()], but for mutable slices.
+    fn as_coeffs_mut(elements: &mut [Self]) -> &mut [B] {
+        assert_coeff_layout::<B>();
+        let ptr = elements.as_mut_ptr();
+        let len = elements.len() * 3;
+        unsafe { slice::from_raw_parts_mut(ptr as *mut B, len) }
+    }
+
+    /// Re-interprets a vector of base elements as a vector of cubic extension elements without
+    /// copying.
+    ///
+    /// Both the length and the capacity of the source vector must be divisible by three; this is
+    /// checked by [CubeExtension::from_base_vector()] before this function is called.
+    fn base_to_cubic_vector(source: Vec<B>) -> Vec<Self> {
+        debug_assert_eq!(
+            source.len() % 3,
+            0,
+            "source length must be divisible by three"
+        );
+        debug_assert_eq!(
+            source.capacity() % 3,
+            0,
+            "source capacity must be divisible by three"
+        );
+        assert_coeff_layout::<B>();
+        let mut v = core::mem::ManuallyDrop::new(source);
+        let p = v.as_mut_ptr();
+        let len = v.len() / 3;
+        let cap = v.capacity() / 3;
+        unsafe { Vec::from_raw_parts(p as *mut Self, len, cap) }
+    }
+}
+
+impl<B: StarkField + ExtensibleField<3>> CubeExtension<B> {
+    /// Returns `a` if `choice` is false, and `b` if `choice` is true.
+    ///
+    /// The selection is performed coordinate-wise using constant-time selection in the base
+    /// field, and thus, does not branch on `choice` or on the values of `a` and `b`.
+    #[inline]
+    pub fn select(a: Self, b: Self, choice: bool) -> Self {
+        Self(
+            B::select(a.0, b.0, choice),
+            B::select(a.1, b.1, choice),
+            B::select(a.2, b.2, choice),
+        )
+    }
+
+    /// Returns true if this element and `other` are equal.
+    ///
+    /// Unlike `==`, which stops at the first pair of coordinates that differ, this performs a
+    /// constant-time comparison of all coordinates in the base field and combines the results
+    /// without branching, so the time it takes does not depend on where the elements differ.
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        // use non-short-circuiting `&` so that all coordinates are always compared
+        self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1) & self.2.ct_eq(&other.2)
+    }
+
+    /// Adds `value` to this element if `add` is true, and leaves this element unchanged
+    /// otherwise.
+    ///
+    /// The addition is always performed: when `add` is false, ZERO is added instead of `value`.
+    /// The addend is chosen via [CubeExtension::select()], and thus, this does not branch on
+    /// `add`.
+    #[inline]
+    pub fn cond_add(&mut self, value: Self, add: bool) {
+        *self += Self::select(Self::ZERO, value, add);
+    }
+
+    /// Returns an element equal to this element with all coordinates reduced to a unique
+    /// internal representation.
+    ///
+    /// Base field elements may have several internal representations of the same value, and
+    /// thus, arithmetically equal elements may have different [AsBytes] representations. After
+    /// normalization, equal elements are guaranteed to have identical bytes. This is a no-op for
+    /// base fields with canonical representation.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        if B::IS_CANONICAL {
+            return self;
+        }
+        Self(self.0.normalize(), self.1.normalize(), self.2.normalize())
+    }
+
+    /// Normalizes all elements of the provided slice in place.
+    ///
+    /// See [CubeExtension::normalize()] for details.
+    pub fn normalize_slice(values: &mut [Self]) {
+        if B::IS_CANONICAL {
+            return;
+        }
+        for value in Self::as_coeffs_mut(values) {
+            *value = value.normalize();
+        }
+    }
+
+    /// Compares canonical integer values of the α coordinates of this element and `other`.
+    ///
+    /// This is intended for bucketing elements by their first coordinate only: the β and γ
+    /// coordinates are ignored, and thus, `Ordering::Equal` does not imply that the elements are
+    /// equal. Use [Ord::cmp()] for a total ordering consistent with equality.
+    pub fn cmp_by_a(&self, other: &Self) -> Ordering {
+        cmp_canonical(self.0, other.0)
+    }
+
+    /// Returns a multiplicative inverse of this element, or ZERO if this element is ZERO.
+    ///
+    /// Unlike [FieldElement::inv()], this does not return early for ZERO: the inverse is always
+    /// computed from the norm of the element, relying on the base field inversion to map ZERO to
+    /// ZERO. Thus, the time it takes does not depend on whether this element is ZERO, as long as
+    /// the inversion in the base field is constant-time.
+    #[inline]
+    #[must_use]
+    pub fn inv_ct(self) -> Self {
+        // for ZERO, both the numerator and the norm are ZERO, and so is the result
+        let (numerator, norm) = self.norm_with_numerator();
+        let denom_inv = norm.inv();
+
+        Self(
+            numerator[0] * denom_inv,
+            numerator[1] * denom_inv,
+            numerator[2] * denom_inv,
+        )
+    }
+
+    /// Returns a square root of this element if it exists, or None otherwise.
+    ///
+    /// The square root is computed using Tonelli-Shanks algorithm adapted to the multiplicative
+    /// group of the extension field (i.e., the group of order p^3 - 1). The parameters of this
+    /// group are supplied by [ExtensibleField::sqrt_params()] of the base field.
+    ///
+    /// # Panics
+    /// Panics if square roots are not supported for cubic extensions of the base field.
+    pub fn sqrt(&self) -> Option<Self> {
+        let (two_adicity, cofactor) = <B as ExtensibleField<3>>::sqrt_params()
+            .expect("square roots are not supported for this extension field");
+
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+
+        // since the extension has odd degree, a non-residue in the base field (e.g., a generator
+        // of the base field) is also a non-residue in the extension field
+        let mut c = Self::from(B::GENERATOR).exp_limbs(cofactor);
+
+        // compute (t + 1) / 2; since t is odd, this is the same as (t >> 1) + 1
+        let mut half_cofactor = cofactor.to_vec();
+        for i in 0..half_cofactor.len() {
+            let carry = half_cofactor.get(i + 1).map_or(0, |&limb| limb << 63);
+            half_cofactor[i] = (half_cofactor[i] >> 1) | carry;
+        }
+        let mut r = self.exp_limbs(&half_cofactor) * *self;
+        let mut t = self.exp_limbs(cofactor);
+        let mut m = two_adicity;
+
+        while t != Self::ONE {
+            // find the least i such that t^(2^i) = 1
+            let mut i = 0;
+            let mut t2i = t;
+            while t2i != Self::ONE {
+                t2i = t2i.square();
+                i += 1;
+                if i == m {
+                    // self is a quadratic non-residue
+                    return None;
+                }
+            }
+
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+            m = i;
+            c = b.square();
+            t *= c;
+            r *= b;
+        }
+
+        Some(r)
+    }
+
+    /// Returns a multiplicative inverse of this element, or None if this element is ZERO.
+    ///
+    /// Unlike [FieldElement::inv()], which returns ZERO for ZERO, this method makes inversion of
+    /// ZERO explicit; [FieldElement::inv()] should be preferred in performance-sensitive code
+    /// where ZERO is known not to occur.
+    pub fn inv_checked(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.inv())
+        }
+    }
+
+    /// Returns the result of dividing this element by `rhs`, or None if `rhs` is ZERO.
+    ///
+    /// Unlike [Div](core::ops::Div), which in release builds silently returns ZERO when `rhs` is
+    /// ZERO (since inversion maps ZERO to ZERO), this method makes division by ZERO explicit.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        rhs.inv_checked().map(|rhs_inv| self * rhs_inv)
+    }
+
+    /// Returns multiplicative inverses of all provided elements.
+    ///
+    /// Inversion is performed using Montgomery's batch inversion trick, and thus, requires only a
+    /// single inversion in the extension field (and, hence, a single inversion in the base field)
+    /// and O(n) multiplications. Any ZEROs in the provided slice are left as ZEROs in the output.
+    ///
+    /// When `concurrent` feature is enabled, the inversion is performed concurrently in multiple
+    /// threads.
+    pub fn batch_inv(elements: &[Self]) -> Vec<Self> {
+        batch_inversion(elements)
+    }
+
+    /// Returns multiplicative inverses of all provided elements, or the index of the first ZERO
+    /// in the provided slice.
+    ///
+    /// Unlike [CubeExtension::batch_inv()], which leaves ZEROs in place, this treats ZERO as an
+    /// error; this is useful when none of the elements are expected to be ZERO, and the offending
+    /// element needs to be identified.
+    ///
+    /// # Errors
+    /// Returns the index of the first ZERO element if any of the elements are ZERO.
+    pub fn try_batch_inv(elements: &[Self]) -> Result<Vec<Self>, usize> {
+        match elements.iter().position(|element| element.is_zero()) {
+            Some(index) => Err(index),
+            None => Ok(batch_inversion(elements)),
+        }
+    }
+
+    /// Returns multiplicative inverses of all provided elements together with the norms of the
+    /// elements relative to the base field.
+    ///
+    /// For each element, the norm and the product of its two Galois conjugates are computed in a
+    /// single pass. The norms are then inverted in the base field using Montgomery's batch
+    /// inversion trick, and each inverse is obtained by multiplying the product of conjugates by
+    /// the inverse of the norm. ZEROs in the provided slice have ZERO norms, and are left as
+    /// ZEROs in the output.
+    ///
+    /// When `concurrent` feature is enabled, the inversion of the norms is performed
+    /// concurrently in multiple threads.
+    pub fn batch_inv_with_norms(elements: &[Self]) -> (Vec<Self>, Vec<B>) {
+        let (numerators, norms): (Vec<[B; 3]>, Vec<B>) = elements
+            .iter()
+            .map(|element| element.norm_with_numerator())
+            .unzip();
+
+        let inverses = numerators
+            .into_iter()
+            .zip(batch_inversion(&norms))
+            .map(|(numerator, norm_inv)| {
+                Self(
+                    numerator[0] * norm_inv,
+                    numerator[1] * norm_inv,
+                    numerator[2] * norm_inv,
+                )
+            })
+            .collect();
+
+        (inverses, norms)
+    }
+
+    /// Permutes the provided elements into bit-reversed order in place.
+    ///
+    /// Each extension element is moved as a whole, and thus, when the slice is re-interpreted as
+    /// a slice of base elements, the coordinates of every element stay grouped together. This is
+    /// the same permutation which is applied to FFT outputs in the [fft](crate::fft) module.
+    ///
+    /// # Panics
+    /// Panics if the number of elements is not a power of two.
+    pub fn permute_bit_reversed(elements: &mut [Self]) {
+        assert!(
+            elements.is_empty() || elements.len().is_power_of_two(),
+            "number of elements must be a power of two, but was {}",
+            elements.len()
+        );
+        fft::permute(elements);
+    }
+
+    /// Reverts the permutation applied by [CubeExtension::permute_bit_reversed()] in place.
+    ///
+    /// Bit-reversal permutation is an involution, and thus, this is the same as applying
+    /// [CubeExtension::permute_bit_reversed()] again.
+    ///
+    /// # Panics
+    /// Panics if the number of elements is not a power of two.
+    pub fn unpermute_bit_reversed(elements: &mut [Self]) {
+        Self::permute_bit_reversed(elements);
+    }
+
+    /// Evaluates a polynomial at the specified point, where the coefficients of the polynomial
+    /// are provided in bit-reversed order (e.g., as they are left by some FFT implementations).
+    ///
+    /// The evaluation is performed using Horner's method, reading the coefficients at their
+    /// bit-reversed positions; thus, the result is the same as evaluating the polynomial via
+    /// [polynom::eval()](crate::polynom::eval) after applying
+    /// [CubeExtension::unpermute_bit_reversed()] to the coefficients, but no permutation is
+    /// performed. For an empty list of coefficients, ZERO is returned.
+    ///
+    /// # Panics
+    /// Panics if the number of coefficients is not a power of two.
+    pub fn eval_bit_reversed(coeffs: &[Self], x: Self) -> Self {
+        let n = coeffs.len();
+        assert!(
+            n == 0 || n.is_power_of_two(),
+            "number of coefficients must be a power of two, but was {}",
+            n
+        );
+        (0..n).rev().fold(Self::ZERO, |acc, i| {
+            acc * x + coeffs[fft::permute_index(n, i)]
+        })
+    }
+
+    /// Returns the bit decomposition of this element.
+    ///
+    /// The canonical integer representation of each coordinate is decomposed into
+    /// [MODULUS_BITS](StarkField::MODULUS_BITS) little-endian bits, and the decompositions of α,
+    /// β, and γ are concatenated in this order; thus, the result always contains exactly
+    /// 3 * `MODULUS_BITS` bits.
+    pub fn to_bits(&self) -> Vec<bool> {
+        let one = B::PositiveInteger::from(1u32);
+        let mut result = Vec::with_capacity(3 * B::MODULUS_BITS as usize);
+        for coord in [self.0, self.1, self.2] {
+            let value = coord.as_int();
+            result.extend((0..B::MODULUS_BITS).map(|i| (value >> i) & one == one));
+        }
+        result
+    }
+
+    /// Returns an element reconstructed from the provided bit decomposition.
+    ///
+    /// This is the inverse of [CubeExtension::to_bits()]: the bits are expected to consist of
+    /// 3 * [MODULUS_BITS](StarkField::MODULUS_BITS) little-endian bits of α, β, and γ, in this
+    /// order.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of bits is not equal to 3 * `MODULUS_BITS`.
+    /// * Any of the coordinates encodes a value greater than or equal to the field modulus.
+    pub fn from_bits(bits: &[bool]) -> Result<Self, DeserializationError> {
+        let num_coord_bits = B::MODULUS_BITS as usize;
+        if bits.len() != 3 * num_coord_bits {
+            return Err(DeserializationError::InvalidValue(format!(
+                "expected {} bits for a field element, but was {} bits",
+                3 * num_coord_bits,
+                bits.len(),
+            )));
+        }
+
+        let mut coords = [B::ZERO; 3];
+        for (coord, coord_bits) in coords.iter_mut().zip(bits.chunks_exact(num_coord_bits)) {
+            // pack the bits into the little-endian byte encoding of the coordinate, and read it
+            // back to make sure the value is checked against the modulus
+            let mut bytes = vec![0u8; B::ELEMENT_BYTES];
+            for (i, &bit) in coord_bits.iter().enumerate() {
+                bytes[i / 8] |= (bit as u8) << (i % 8);
+            }
+            *coord = B::read_from(&mut SliceReader::new(&bytes))?;
+        }
+        Ok(Self::from_array(coords))
+    }
+
+    /// Returns this element raised to the power 2^k.
+    ///
+    /// The result is computed by squaring this element `k` times, and thus, no exponent needs to
+    /// be materialized; this works for any `k`, including values for which 2^k would overflow
+    /// an integer type. For `k` = 0, this element is returned.
+    #[inline]
+    #[must_use]
+    pub fn pow_2k(self, k: usize) -> Self {
+        let mut result = self;
+        for _ in 0..k {
+            result = result.square();
+        }
+        result
+    }
+
+    /// Returns true if the multiplicative order of this element divides `k`, i.e., if this
+    /// element raised to the power `k` is ONE.
+    ///
+    /// For ZERO, this returns true only for `k` = 0.
+    pub fn order_divides(&self, k: B::PositiveInteger) -> bool {
+        self.exp(k) == Self::ONE
+    }
+
+    /// Returns true if this element is an n-th power of some element of the extension field.
+    ///
+    /// A non-zero element x is an n-th power if and only if x^((p^3 - 1) / d) = 1, where p is
+    /// the order of the base field, and d = gcd(n, p^3 - 1); when n divides p^3 - 1, this is the
+    /// same as checking x^((p^3 - 1) / n) = 1. ZERO is an n-th power for any n.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn is_nth_power(&self, n: u64) -> bool {
+        assert!(n != 0, "n must be greater than zero");
+        if *self == Self::ZERO {
+            return true;
+        }
+
+        // p^3 - 1 does not fit into B::PositiveInteger, and thus, it is represented as 64-bit
+        // limbs in little-endian order
+        let modulus = modulus_limbs::<B>();
+        let mut group_order = mul_limbs(&mul_limbs(&modulus, &modulus), &modulus);
+        for limb in group_order.iter_mut() {
+            let (value, borrow) = limb.overflowing_sub(1);
+            *limb = value;
+            if !borrow {
+                break;
+            }
+        }
+
+        let (_, remainder) = div_rem_limbs(&group_order, n);
+        let (mut a, mut b) = (n, remainder);
+        while b != 0 {
+            let t = a % b;
+            a = b;
+            b = t;
+        }
+        let (exponent, _) = div_rem_limbs(&group_order, a);
+        self.exp_limbs(&exponent) == Self::ONE
+    }
+
+    /// Evaluates the vanishing polynomial of a multiplicative coset at `x`.
+    ///
+    /// The coset is defined as `domain_offset` * H, where H is the multiplicative subgroup of size
+    /// `domain_size`; its vanishing polynomial is x^n - `domain_offset`^n, where n is
+    /// `domain_size`. Exponentiation of the offset is performed in the base field, and when
+    /// `domain_size` is a power of two, `x` is raised to the n-th power via repeated squaring.
+    ///
+    /// # Panics
+    /// Panics if `domain_size` is zero.
+    pub fn evaluate_vanishing(x: Self, domain_size: usize, domain_offset: B) -> Self {
+        assert!(domain_size > 0, "domain size must be greater than zero");
+        let x_pow = if domain_size.is_power_of_two() {
+            x.pow_2k(domain_size.trailing_zeros() as usize)
+        } else {
+            x.exp_limbs(&[domain_size as u64])
+        };
+        let offset_pow = domain_offset.exp(B::PositiveInteger::from(domain_size as u64));
+        x_pow.sub_base(offset_pow)
+    }
+
+    /// Returns coefficients of a polynomial with coefficients in the extension field which
+    /// evaluates to `evals` at the corresponding points of the base field `domain`.
+    ///
+    /// Uses [Lagrange interpolation](https://en.wikipedia.org/wiki/Lagrange_polynomial) in the
+    /// same way as [polynom::interpolate()](crate::polynom::interpolate) does, but since the
+    /// domain is in the base field, the Lagrange basis polynomials and their inverse
+    /// denominators are computed and kept in the base field; only the accumulation of the result
+    /// is performed in the extension field. The length of the result is equal to the number of
+    /// domain points.
+    ///
+    /// All points in the `domain` are expected to be distinct.
+    ///
+    /// # Panics
+    /// Panics if the number of domain points and evaluations is not the same.
+    pub fn interpolate_from_base_domain(domain: &[B], evals: &[Self]) -> Vec<Self> {
+        assert_eq!(
+            domain.len(),
+            evals.len(),
+            "number of domain points and evaluations must be the same"
+        );
+
+        let roots = polynom::get_zero_roots(domain);
+        let numerators: Vec<Vec<B>> = domain
+            .iter()
+            .map(|&x| polynom::syn_div(&roots, 1, x))
+            .collect();
+
+        let denominators: Vec<B> = numerators
+            .iter()
+            .zip(domain)
+            .map(|(e, &x)| polynom::eval(e, x))
+            .collect();
+        let denominators = batch_inversion(&denominators);
+
+        let mut result = vec![Self(B::ZERO, B::ZERO, B::ZERO); domain.len()];
+        for i in 0..domain.len() {
+            let y_slice = evals[i].mul_base(denominators[i]);
+            for (j, res) in result.iter_mut().enumerate() {
+                *res += y_slice.mul_base(numerators[i][j]);
+            }
+        }
+        result
+    }
+
+    /// Returns values of all Lagrange basis polynomials for the base field `domain` at the
+    /// extension field point `z`; i.e., the i-th element of the result is L_i(z), where L_i is
+    /// the polynomial which evaluates to ONE at `domain[i]` and to ZERO at all other domain
+    /// points.
+    ///
+    /// The values are computed using the barycentric formula L_i(z) = l(z) / ((z - x_i) * d_i),
+    /// where l(z) is the product of (z - x_j) over all domain points x_j, and d_i is the product
+    /// of (x_i - x_j) over all x_j other than x_i. The products d_i are computed in the base
+    /// field, and all denominators are inverted using a single batch inversion. If `z` is one
+    /// of the domain points, the corresponding unit vector is returned.
+    ///
+    /// All points in the `domain` are expected to be distinct.
+    pub fn lagrange_basis_at(domain: &[B], z: Self) -> Vec<Self> {
+        // the barycentric formula is not defined at the domain points themselves
+        if let Some(z) = z.to_base() {
+            if let Some(k) = domain.iter().position(|&x| x == z) {
+                let mut result = vec![Self::ZERO; domain.len()];
+                result[k] = Self::ONE;
+                return result;
+            }
+        }
+
+        let diffs: Vec<Self> = domain.iter().map(|&x| z.sub_base(x)).collect();
+        let vanishing = diffs.iter().fold(Self::ONE, |acc, &diff| acc * diff);
+
+        let denominators: Vec<Self> = diffs
+            .iter()
+            .zip(domain)
+            .enumerate()
+            .map(|(i, (&diff, &x_i))| {
+                let weight = domain
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .fold(B::ONE, |acc, (_, &x_j)| acc * (x_i - x_j));
+                diff.mul_base(weight)
+            })
+            .collect();
+
+        batch_inversion(&denominators)
+            .into_iter()
+            .map(|inv| inv * vanishing)
+            .collect()
+    }
+
+    /// Returns an iterator over all elements of this extension field.
+    ///
+    /// Every element is yielded exactly once, starting with ZERO; the coordinates are enumerated
+    /// as digits of a base `p` counter with the first coordinate being the least significant one,
+    /// where `p` is the order of the base field. The order of the iteration is thus reproducible.
+    ///
+    /// # Panics
+    /// Panics if the base field does not specify
+    /// [SMALL_FIELD_ORDER](ExtensibleField::SMALL_FIELD_ORDER), or if the number of elements in
+    /// the extension field does not fit into 64 bits.
+    pub fn all_elements() -> impl Iterator<Item = Self> {
+        let order = <B as ExtensibleField<3>>::SMALL_FIELD_ORDER
+            .expect("all elements can be enumerated only for fields with small order");
+        let num_elements = order
+            .checked_pow(3)
+            .expect("number of elements in the extension field must fit into 64 bits");
+
+        (0..num_elements).map(move |i| {
+            Self(
+                B::from(i % order),
+                B::from(i / order % order),
+                B::from(i / order / order),
+            )
+        })
+    }
+
+    /// Returns a random non-ZERO element.
+    ///
+    /// Elements are drawn via [rand_value()](rand_utils::rand_value), and draws which yield
+    /// ZERO are retried. Since ZERO is drawn with a negligible probability, this is meant for
+    /// tests and blinding factors which must not be ZERO.
+    ///
+    /// # Panics
+    /// Panics if a non-ZERO element could not be drawn after 1000 attempts; this happens only if
+    /// the source of randomness is broken.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn rand_nonzero() -> Self {
+        for _ in 0..MAX_RAND_NONZERO_ATTEMPTS {
+            let value: Self = rand_value();
+            if value != Self::ZERO {
+                return value;
+            }
+        }
+        panic!(
+            "failed to draw a non-zero element after {} attempts",
+            MAX_RAND_NONZERO_ATTEMPTS
+        );
+    }
+
+    /// Returns a random non-ZERO element built from bytes supplied by `fill_bytes`, or None if
+    /// such an element could not be drawn after 1000 attempts.
+    ///
+    /// This is a variant of [CubeExtension::rand_nonzero()] for callers which provide their own
+    /// source of randomness (e.g., a seeded PRNG, or in `no_std` environments). On every attempt,
+    /// `fill_bytes` is called to fill a buffer of [Randomizable::VALUE_SIZE] bytes, which is then
+    /// converted into an element via [Randomizable::from_random_bytes()]; attempts which fail to
+    /// produce an element, or produce ZERO, are retried.
+    pub fn rand_nonzero_with_bytes<F: FnMut(&mut [u8])>(mut fill_bytes: F) -> Option<Self> {
+        let mut bytes = vec![0u8; <Self as Randomizable>::VALUE_SIZE];
+        for _ in 0..MAX_RAND_NONZERO_ATTEMPTS {
+            fill_bytes(&mut bytes);
+            if let Some(value) = Self::from_random_bytes(&bytes) {
+                if value != Self::ZERO {
+                    return Some(value);
+                }
+            }
+        }
+        None
+    }
+
+    /// Reads an element from the `source`, and makes sure that each of its coordinates is encoded
+    /// canonically.
+    ///
+    /// Unlike [Deserializable::read_from()], which relies on the base field to validate its
+    /// encoding, this method explicitly checks that every coordinate is the canonical encoding of
+    /// a value in the range [0, p); this should be used for untrusted inputs (e.g., by the
+    /// verifier).
+    ///
+    /// # Errors
+    /// Returns an error if the source does not contain enough bytes or if any of the coordinates
+    /// is not encoded canonically.
+    pub fn read_canonical_from<R: ByteReader>(
+        source: &mut R,
+    ) -> Result<Self, DeserializationError> {
+        let value0 = read_canonical_base(source)?;
+        let value1 = read_canonical_base(source)?;
+        let value2 = read_canonical_base(source)?;
+        Ok(Self(value0, value1, value2))
+    }
+
+    /// Returns a vector of `n` elements built from consecutive chunks of [Randomizable::VALUE_SIZE]
+    /// bytes at the start of `bytes`, or None if any of the chunks could not be converted into
+    /// an element.
+    ///
+    /// Exactly `n` * [Randomizable::VALUE_SIZE] bytes are consumed; any bytes beyond that are
+    /// ignored. None is also returned if `bytes` does not contain enough bytes for `n` elements.
+    pub fn batch_from_random_bytes(bytes: &[u8], n: usize) -> Option<Vec<Self>> {
+        let value_size = <Self as Randomizable>::VALUE_SIZE;
+        let num_bytes = n.checked_mul(value_size)?;
+        if bytes.len() < num_bytes {
+            return None;
+        }
+        bytes[..num_bytes]
+            .chunks_exact(value_size)
+            .map(Self::from_random_bytes)
+            .collect()
+    }
+
+    /// Returns an element built from the first chunk of [Randomizable::VALUE_SIZE] bytes at the
+    /// start of `bytes` which can be converted into an element, trying at most `max_tries`
+    /// consecutive chunks.
+    ///
+    /// The i-th attempt always uses bytes at offset i * [Randomizable::VALUE_SIZE]; thus, the
+    /// number of bytes consumed depends only on the number of rejected chunks, and not on the
+    /// length of `bytes`. None is returned if all `max_tries` attempts are rejected, or if
+    /// `bytes` runs out before an element could be drawn.
+    pub fn from_random_bytes_bounded(bytes: &[u8], max_tries: usize) -> Option<Self> {
+        bytes
+            .chunks_exact(<Self as Randomizable>::VALUE_SIZE)
+            .take(max_tries)
+            .find_map(Self::from_random_bytes)
+    }
+
+    /// Returns an element built from `bytes` by splitting them into three equal chunks and
+    /// reducing each chunk into a coordinate via [StarkField::reduce_le_bytes()].
+    ///
+    /// Unlike [Randomizable::from_random_bytes()], no inputs are rejected, and thus, no retries
+    /// are needed. For uniformly random `bytes`, the statistical distance of the result from
+    /// uniform is at most 3 * 2^-k, where k is the number of bits by which a chunk exceeds the
+    /// size of the base field modulus; e.g., for 64-bit fields, 64 bytes (of which the first 63
+    /// are used) give k > 100.
+    ///
+    /// Any bytes beyond the largest multiple of three are ignored; an empty chunk is reduced to
+    /// ZERO.
+    pub fn from_wide_bytes(bytes: &[u8]) -> Self {
+        let n = bytes.len() / 3;
+        Self(
+            B::reduce_le_bytes(&bytes[..n]),
+            B::reduce_le_bytes(&bytes[n..2 * n]),
+            B::reduce_le_bytes(&bytes[2 * n..3 * n]),
+        )
+    }
+
+    /// Returns a string representation of this element in which every coordinate is printed as
+    /// its canonical integer residue in the range [0, p).
+    ///
+    /// The output is the same as that of [Display](fmt::Display) for base fields which print
+    /// their canonical values, but unlike [Display](fmt::Display), it never depends on the
+    /// internal encoding (e.g., Montgomery form) of the base field.
+    pub fn to_canonical_string(&self) -> String {
+        // PositiveInteger is only guaranteed to implement Debug; for primitive integer types
+        // this prints the same digits as Display does
+        format!(
+            "({:?}, {:?}, {:?})",
+            self.0.as_int(),
+            self.1.as_int(),
+            self.2.as_int()
+        )
+    }
+
+    /// Checks that the cubic extension arithmetic defined by the base field is self-consistent.
+    ///
+    /// Inversion (and thus, division) of extension elements relies on
+    /// [ExtensibleField::frobenius()] and [ExtensibleField::mul()] agreeing with each other. This
+    /// function checks that for a set of pseudo-random elements x:
+    /// * applying the Frobenius automorphism three times yields x;
+    /// * the product of x and its two Galois conjugates lies in the base field;
+    /// * x * x.inv() is ONE.
+    ///
+    /// The elements are derived from a fixed seed, and thus, the checks are deterministic. This
+    /// is intended to be called from tests of base fields which implement `ExtensibleField<3>`.
+    ///
+    /// # Errors
+    /// Returns an error describing the first failed check, or an error if the base field does
+    /// not support cubic extensions.
+    pub fn debug_validate() -> Result<(), String> {
+        if !Self::is_supported() {
+            return Err("cubic extension is not supported by the base field".to_string());
+        }
+
+        // derive the elements via SplitMix64 so that no source of randomness is required
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next_int = || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+
+        for _ in 0..16 {
+            let x = Self::from_ints(next_int(), next_int(), next_int());
+            if x == Self::ZERO {
+                continue;
+            }
+
+            let [c0, c1, c2] = x.conjugates();
+            if c2.frobenius() != x {
+                return Err(format!(
+                    "applying frobenius three times to {} did not yield the same element",
+                    x
+                ));
+            }
+
+            // the norm is computed here directly, rather than via CubeExtension::norm(), so that
+            // an inconsistency is reported as an error rather than a failed debug assertion
+            let norm = c0 * c1 * c2;
+            if !norm.is_in_base_field() {
+                return Err(format!(
+                    "norm of {} is {}, which is not in the base field",
+                    x, norm
+                ));
+            }
+
+            let product = x * x.inv();
+            if product != Self::ONE {
+                return Err(format!(
+                    "product of {} and its inverse is {} rather than ONE",
+                    x, product
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Exponentiates this element by a power specified as a sequence of 64-bit limbs in
+    /// little-endian order.
+    fn exp_limbs(self, power: &[u64]) -> Self {
+        let mut r = Self::ONE;
+        for &limb in power.iter().rev() {
+            for i in (0..64).rev() {
+                r = r.square();
+                if (limb >> i) & 1 == 1 {
+                    r *= self;
+                }
+            }
+        }
+        r
+    }
+}
+
+// CONST CONSTRUCTORS
+// ------------------------------------------------------------------------------------------------
+
+/// Implements a `const` constructor of cubic extension elements over a base field which provides
+/// a `const fn new(u64)` constructor. Generic functions cannot be `const` because constructors of
+/// base elements are not trait methods, and thus, this is implemented for each base field
+/// separately.
+macro_rules! impl_const_constructor {
+    ($base:ty) => {
+        impl CubeExtension<$base> {
+            /// Returns a new extension element instantiated from the provided integers; values
+            /// which are greater than or equal to the modulus of the base field are silently
+            /// reduced.
+            ///
+            /// Unlike [CubeExtension::from_ints()], this can be evaluated at compile time, and
+            /// thus, can be used to define constants and static tables of extension elements.
+            pub const fn from_u64_coords(a: u64, b: u64, c: u64) -> Self {
+                Self(<$base>::new(a), <$base>::new(b), <$base>::new(c))
+            }
+        }
+    };
+}
+
+impl_const_constructor!(crate::field::f61::BaseElement);
+impl_const_constructor!(crate::field::f62::BaseElement);
+impl_const_constructor!(crate::field::f64::BaseElement);
+#[cfg(test)]
+impl_const_constructor!(crate::field::f7::BaseElement);
+
+// MONTGOMERY ENCODING
+// ------------------------------------------------------------------------------------------------
+
+impl<B: MontgomeryField + ExtensibleField<3>> CubeExtension<B> {
+    /// Returns the encoding of this element in which every coordinate is encoded in Montgomery
+    /// form via [MontgomeryField::write_montgomery_into()], starting with α, then β, then γ.
+    ///
+    /// This encoding is not compatible with the canonical encoding used by the [Serializable]
+    /// implementation; it is intended for interoperability with libraries which encode field
+    /// elements in Montgomery form.
+    pub fn to_montgomery_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(Self::ELEMENT_BYTES);
+        self.0.write_montgomery_into(&mut result);
+        self.1.write_montgomery_into(&mut result);
+        self.2.write_montgomery_into(&mut result);
+        result
+    }
+
+    /// Returns an element decoded from `bytes` encoded via
+    /// [CubeExtension::to_montgomery_bytes()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The length of `bytes` is not equal to the number of bytes in an extension element.
+    /// * Any of the coordinates is not a valid Montgomery encoding of a base field element.
+    pub fn from_montgomery_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "expected {} bytes for a field element, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        let mut reader = SliceReader::new(bytes);
+        let value0 = B::read_montgomery_from(&mut reader)?;
+        let value1 = B::read_montgomery_from(&mut reader)?;
+        let value2 = B::read_montgomery_from(&mut reader)?;
+        Ok(Self(value0, value1, value2))
     }
 }
 
-impl<B: ExtensibleField<3>> Deserializable for CubeExtension<B> {
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let value0 = B::read_from(source)?;
-        let value1 = B::read_from(source)?;
-        let value2 = B::read_from(source)?;
-        Ok(Self(value0, value1, value2))
+impl<B> FieldElement for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    type PositiveInteger = B::PositiveInteger;
+    type BaseField = B::BaseField;
+
+    // computed with checked multiplication so that a base field with pathologically large
+    // elements fails at compile time rather than silently wrapping
+    const ELEMENT_BYTES: usize = match B::ELEMENT_BYTES.checked_mul(3) {
+        Some(num_bytes) => num_bytes,
+        None => panic!("size of cubic extension element does not fit into usize"),
+    };
+    const EXTENSION_DEGREE: usize = B::EXTENSION_DEGREE * 3;
+    const IS_CANONICAL: bool = B::IS_CANONICAL;
+    const ZERO: Self = Self(B::ZERO, B::ZERO, B::ZERO);
+    const ONE: Self = Self(B::ONE, B::ZERO, B::ZERO);
+
+    #[inline]
+    fn double(self) -> Self {
+        Self(self.0.double(), self.1.double(), self.2.double())
+    }
+
+    #[inline]
+    fn square(self) -> Self {
+        let result = <B as ExtensibleField<3>>::square([self.0, self.1, self.2]);
+        Self(result[0], result[1], result[2])
+    }
+
+    #[inline]
+    fn mul_base(self, rhs: Self::BaseField) -> Self {
+        let rhs = B::from(rhs);
+        Self::from_array(<B as ExtensibleField<3>>::mul_base(self.to_array(), rhs))
+    }
+
+    fn exp(self, power: Self::PositiveInteger) -> Self {
+        // exponents with at most this many bits are handled via plain square-and-multiply since
+        // for them the cost of building the window table is not amortized
+        const MIN_WINDOWED_BITS: u32 = 32;
+
+        let int_zero = Self::PositiveInteger::from(0u32);
+        let int_one = Self::PositiveInteger::from(1u32);
+
+        if power == int_zero {
+            return Self::ONE;
+        } else if self == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        // determine the number of significant bits in the exponent
+        let mut num_bits = 0;
+        let mut p = power;
+        while p > int_zero {
+            p >>= int_one;
+            num_bits += 1;
+        }
+
+        if num_bits <= MIN_WINDOWED_BITS {
+            let mut r = Self::ONE;
+            let mut b = self;
+            let mut p = power;
+            while p > int_zero {
+                if p & int_one == int_one {
+                    r *= b;
+                }
+                p >>= int_one;
+                b = b.square();
+            }
+            return r;
+        }
+
+        // use fixed 4-bit windows; table[i] contains self^i
+        let mut table = [Self::ONE; 16];
+        for i in 1..16 {
+            table[i] = table[i - 1] * self;
+        }
+
+        let num_windows = (num_bits + 3) / 4;
+        let mut r = Self::ONE;
+        for window in (0..num_windows).rev() {
+            if window != num_windows - 1 {
+                r = r.square().square().square().square();
+            }
+
+            let mut digit = 0;
+            for i in 0..4 {
+                let bit = window * 4 + i;
+                if bit < num_bits && (power >> bit) & int_one == int_one {
+                    digit |= 1 << i;
+                }
+            }
+            if digit != 0 {
+                r *= table[digit];
+            }
+        }
+
+        r
+    }
+
+    #[inline]
+    fn inv(self) -> Self {
+        if self == Self::ZERO {
+            return self;
+        }
+
+        let (numerator, norm) = self.norm_with_numerator();
+        let denom_inv = norm.inv();
+
+        Self(
+            numerator[0] * denom_inv,
+            numerator[1] * denom_inv,
+            numerator[2] * denom_inv,
+        )
+    }
+
+    #[inline]
+    fn conjugate(&self) -> Self {
+        self.frobenius()
+    }
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        // on big-endian targets, the in-memory encoding of base elements is not little-endian,
+        // and a borrowed view cannot be byte-swapped; rather than returning bytes which differ
+        // from the canonical encoding, refuse to build the view.
+        if cfg!(target_endian = "big") {
+            panic!("{}", BIG_ENDIAN_BYTE_VIEW_ERROR);
+        }
+
+        // an extension element is laid out as its base coefficients in order, so the byte
+        // encoding of a slice of extension elements is the byte encoding of the underlying
+        // base elements; this keeps the byte order consistent with the base field.
+        let bytes = Self::BaseField::elements_as_bytes(Self::as_base_elements(elements));
+        debug_assert_eq!(
+            checked_byte_count(elements.len(), Self::ELEMENT_BYTES).ok(),
+            Some(bytes.len()),
+            "byte count of elements must not overflow"
+        );
+        bytes
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        // an empty slice does not point to any elements, and thus, its pointer (which may be
+        // dangling or arbitrarily offset) must not be inspected for alignment
+        if bytes.is_empty() {
+            return Ok(&[]);
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+        debug_assert_eq!(checked_byte_count(len, Self::ELEMENT_BYTES)?, bytes.len());
+
+        // make sure the bytes are aligned on the boundary consistent with base element alignment
+        let alignment = Self::BaseField::ELEMENT_BYTES;
+        let offset = (p as usize) % alignment;
+        if offset != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "slice memory alignment is not valid for this field element type; expected \
+                alignment of {} bytes, but pointer offset modulo alignment was {} bytes",
+                alignment, offset,
+            )));
+        }
+
+        assert_coeff_layout::<B>();
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+
+    fn zeroed_vector(n: usize) -> Vec<Self> {
+        // get three times the number of base elements and re-interpret them as cubic field
+        // elements
+        let result = B::zeroed_vector(n * 3);
+        Self::from_base_vector(result).expect("length of zeroed vector is divisible by three")
+    }
+
+    fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        // re-interpret the elements as coefficients in B first, and then let B expose its own
+        // base elements; when B is a StarkField, this is a no-op.
+        B::as_base_elements(Self::as_coeffs(elements))
+    }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        // same as above; the elements are exclusively borrowed for the lifetime of the returned
+        // slice, and thus, no other references into this memory can exist
+        B::as_base_elements_mut(Self::as_coeffs_mut(elements))
+    }
+}
+
+impl<B: StarkField + ExtensibleField<3>> ExtensionOf<B> for CubeExtension<B> {
+    #[inline(always)]
+    fn base_degree() -> usize {
+        3
+    }
+
+    #[inline(always)]
+    fn from_base(b: B) -> Self {
+        Self(b, B::ZERO, B::ZERO)
+    }
+
+    fn to_base_coords(&self) -> Vec<B> {
+        vec![self.0, self.1, self.2]
+    }
+}
+
+impl<B> Randomizable for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::VALUE_SIZE {
+            return None;
+        }
+        // each coordinate is drawn via the base field; see the note in QuadExtension for why
+        // this matters for fields such as f61
+        let mut coords = bytes.chunks_exact(B::ELEMENT_BYTES);
+        let a = B::from_random_bytes(coords.next()?)?;
+        let b = B::from_random_bytes(coords.next()?)?;
+        let c = B::from_random_bytes(coords.next()?)?;
+        Some(Self(a, b, c))
+    }
+}
+
+impl<B: ExtensibleField<3>> fmt::Display for CubeExtension<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.0, self.1, self.2)
+    }
+}
+
+impl<B: ExtensibleField<3>> Hash for CubeExtension<B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // base elements may have multiple internal representations of the same value; to keep
+        // hashing consistent with equality, we hash the canonical serialized form instead.
+        state.write(&self.to_bytes());
+    }
+}
+
+/// Elements are ordered lexicographically by the canonical integer values of their coordinates,
+/// starting with α, then β, then γ. Since canonical values are compared, the ordering does not
+/// depend on the internal representation of the base field elements.
+impl<B: StarkField + ExtensibleField<3>> Ord for CubeExtension<B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_canonical(self.0, other.0)
+            .then_with(|| cmp_canonical(self.1, other.1))
+            .then_with(|| cmp_canonical(self.2, other.2))
+    }
+}
+
+impl<B: StarkField + ExtensibleField<3>> PartialOrd for CubeExtension<B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// OVERLOADED OPERATORS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: ExtensibleField<3>> Add for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl<B: ExtensibleField<3>> AddAssign for CubeExtension<B> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<B: ExtensibleField<3>> Add<B> for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: B) -> Self {
+        self.add_base(rhs)
+    }
+}
+
+impl<B: ExtensibleField<3>> Sub for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl<B: ExtensibleField<3>> SubAssign for CubeExtension<B> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<B: ExtensibleField<3>> Sub<B> for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: B) -> Self {
+        self.sub_base(rhs)
+    }
+}
+
+impl<B: ExtensibleField<3>> Mul for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let result =
+            <B as ExtensibleField<3>>::mul([self.0, self.1, self.2], [rhs.0, rhs.1, rhs.2]);
+        Self(result[0], result[1], result[2])
+    }
+}
+
+impl<B: ExtensibleField<3>> MulAssign for CubeExtension<B> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<B: ExtensibleField<3>> Mul<B> for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: B) -> Self {
+        self.mul_base(rhs)
+    }
+}
+
+impl<B: ExtensibleField<3>> MulAssign<B> for CubeExtension<B> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: B) {
+        *self = self.mul_base(rhs)
+    }
+}
+
+impl<B> Div for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        debug_assert!(!rhs.is_zero(), "cannot divide by ZERO");
+        self * rhs.inv()
+    }
+}
+
+impl<B> DivAssign for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<B: ExtensibleField<3>> Neg for CubeExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2)
+    }
+}
+
+// the operators below take their operands by reference; they are consistent with the by-value
+// versions above and allow the callers to avoid copying (or moving) the operands
+
+impl<B: ExtensibleField<3>> Add<&CubeExtension<B>> for &CubeExtension<B> {
+    type Output = CubeExtension<B>;
+
+    #[inline]
+    fn add(self, rhs: &CubeExtension<B>) -> CubeExtension<B> {
+        CubeExtension(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl<B: ExtensibleField<3>> AddAssign<&Self> for CubeExtension<B> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Self) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+        self.2 += rhs.2;
+    }
+}
+
+impl<B: ExtensibleField<3>> Sub<&CubeExtension<B>> for &CubeExtension<B> {
+    type Output = CubeExtension<B>;
+
+    #[inline]
+    fn sub(self, rhs: &CubeExtension<B>) -> CubeExtension<B> {
+        CubeExtension(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl<B: ExtensibleField<3>> SubAssign<&Self> for CubeExtension<B> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+        self.2 -= rhs.2;
+    }
+}
+
+impl<B: ExtensibleField<3>> Mul<&CubeExtension<B>> for &CubeExtension<B> {
+    type Output = CubeExtension<B>;
+
+    #[inline]
+    fn mul(self, rhs: &CubeExtension<B>) -> CubeExtension<B> {
+        let result =
+            <B as ExtensibleField<3>>::mul([self.0, self.1, self.2], [rhs.0, rhs.1, rhs.2]);
+        CubeExtension(result[0], result[1], result[2])
+    }
+}
+
+impl<B: ExtensibleField<3>> MulAssign<&Self> for CubeExtension<B> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = &*self * rhs
+    }
+}
+
+impl<B> Div<&CubeExtension<B>> for &CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    CubeExtension<B>: From<B::BaseField>,
+{
+    type Output = CubeExtension<B>;
+
+    #[inline]
+    fn div(self, rhs: &CubeExtension<B>) -> CubeExtension<B> {
+        *self / *rhs
+    }
+}
+
+impl<B> DivAssign<&Self> for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: &Self) {
+        *self = *self / *rhs
+    }
+}
+
+impl<B: ExtensibleField<3>> Neg for &CubeExtension<B> {
+    type Output = CubeExtension<B>;
+
+    #[inline]
+    fn neg(self) -> CubeExtension<B> {
+        CubeExtension(-self.0, -self.1, -self.2)
+    }
+}
+
+impl<B: ExtensibleField<3>> Sum for CubeExtension<B> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(B::ZERO, B::ZERO, B::ZERO), |acc, x| acc + x)
+    }
+}
+
+impl<'a, B: ExtensibleField<3>> Sum<&'a Self> for CubeExtension<B> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<B: ExtensibleField<3>> Product for CubeExtension<B> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(B::ONE, B::ZERO, B::ZERO), |acc, x| acc * x)
+    }
+}
+
+impl<'a, B: ExtensibleField<3>> Product<&'a Self> for CubeExtension<B> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+// TYPE CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: ExtensibleField<3>> From<B> for CubeExtension<B> {
+    fn from(value: B) -> Self {
+        Self(value, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: ExtensibleField<3>> From<[B; 3]> for CubeExtension<B> {
+    fn from(value: [B; 3]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+impl<B: ExtensibleField<3>> From<CubeExtension<B>> for [B; 3] {
+    fn from(value: CubeExtension<B>) -> Self {
+        value.to_array()
+    }
+}
+
+impl<B: ExtensibleField<3>, const N: usize> From<CubeExtension<B>> for [u8; N] {
+    /// Converts an extension element into an array of bytes containing the canonical encoding of
+    /// the element in little-endian byte order (i.e., the same bytes as written by
+    /// [Serializable::write_into()]), without allocating on the heap.
+    ///
+    /// The length of the array `N` must be equal to the number of bytes in the encoding of the
+    /// element; this is checked at compile time.
+    fn from(value: CubeExtension<B>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = ByteArrayLength::<B, N>::IS_VALID;
+        let mut result = [0u8; N];
+        value.write_into(&mut ArrayWriter::new(&mut result));
+        result
+    }
+}
+
+impl<B> From<B> for CubeExtension<QuadExtension<B>>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+{
+    fn from(value: B) -> Self {
+        Self::from(QuadExtension::from(value))
+    }
+}
+
+impl<B: ExtensibleField<3>> From<u128> for CubeExtension<B> {
+    fn from(value: u128) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: ExtensibleField<3>> From<u64> for CubeExtension<B> {
+    fn from(value: u64) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: ExtensibleField<3>> From<u32> for CubeExtension<B> {
+    fn from(value: u32) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: ExtensibleField<3>> From<u16> for CubeExtension<B> {
+    fn from(value: u16) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: ExtensibleField<3>> From<u8> for CubeExtension<B> {
+    fn from(value: u8) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO)
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    type Error = DeserializationError;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to be in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "not enough bytes for a full field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        if bytes.len() > Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "too many bytes for a field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        let mut reader = SliceReader::new(bytes);
+        Self::read_from(&mut reader)
+    }
+}
+
+impl<'a, B: ExtensibleField<3>> TryFrom<&'a [B]> for CubeExtension<B> {
+    type Error = DeserializationError;
+
+    /// Converts a slice of exactly three base elements into an extension element; returns error
+    /// if the slice contains any other number of elements.
+    fn try_from(elements: &'a [B]) -> Result<Self, Self::Error> {
+        if elements.len() != 3 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid number of base elements; expected 3 elements, but was {} elements",
+                elements.len(),
+            )));
+        }
+        Ok(Self(elements[0], elements[1], elements[2]))
+    }
+}
+
+impl<B> AsBytes for CubeExtension<B>
+where
+    B: ExtensibleField<3>,
+    Self: From<B::BaseField>,
+{
+    fn as_bytes(&self) -> &[u8] {
+        // delegate to the base field so that coefficients are encoded the same way as base
+        // elements: `self.0` first, followed by `self.1` and `self.2`.
+        Self::elements_as_bytes(slice::from_ref(self))
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl<B: ExtensibleField<3>> Serializable for CubeExtension<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+        self.1.write_into(target);
+        self.2.write_into(target);
+    }
+
+    /// Serializes all elements of the `source` and writes these bytes into the `target`.
+    ///
+    /// When the internal representation of the base field is canonical and the platform is
+    /// little-endian, the in-memory representation of the elements is the same as their
+    /// serialized form; in this case, all bytes are written into the `target` with a single call.
+    /// Otherwise, elements are serialized one by one.
+    fn write_batch_into<W: ByteWriter>(source: &[Self], target: &mut W) {
+        if B::IS_CANONICAL && cfg!(target_endian = "little") {
+            target.write_u8_slice(B::elements_as_bytes(Self::as_coeffs(source)));
+        } else {
+            for element in source {
+                element.write_into(target);
+            }
+        }
+    }
+}
+
+impl<B: ExtensibleField<3>> Deserializable for CubeExtension<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value0 = B::read_from(source)?;
+        let value1 = B::read_from(source)?;
+        let value2 = B::read_from(source)?;
+        Ok(Self(value0, value1, value2))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B> serde::Serialize for CubeExtension<B>
+where
+    B: ExtensibleField<3> + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // serialize as a 3-tuple of base field elements
+        serde::Serialize::serialize(&(self.0, self.1, self.2), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, B> serde::Deserialize<'de> for CubeExtension<B>
+where
+    B: ExtensibleField<3> + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (value0, value1, value2) = <(B, B, B)>::deserialize(deserializer)?;
+        Ok(Self(value0, value1, value2))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Compares canonical integer values of the provided base field elements.
+#[inline]
+fn cmp_canonical<B: StarkField>(a: B, b: B) -> Ordering {
+    let (a, b) = (a.as_int(), b.as_int());
+    if a < b {
+        Ordering::Less
+    } else if a > b {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Returns the number of bytes occupied by `num_elements` elements of `element_bytes` bytes each.
+///
+/// Returns an error if the number of bytes does not fit into usize.
+fn checked_byte_count(
+    num_elements: usize,
+    element_bytes: usize,
+) -> Result<usize, DeserializationError> {
+    num_elements.checked_mul(element_bytes).ok_or_else(|| {
+        DeserializationError::InvalidValue(format!(
+            "byte count of {} elements of {} bytes each does not fit into usize",
+            num_elements, element_bytes
+        ))
+    })
+}
+
+/// Checks at compile time that a cubic extension element over B is laid out exactly as three
+/// consecutive values of B, without any padding.
+///
+/// All functions which re-interpret memory of extension elements as memory of their coefficients
+/// (and vice versa) rely on this; `#[repr(C)]` guarantees the field order, and this check rules
+/// out padding and alignment mismatches.
+struct CoeffLayout<B>(PhantomData<B>);
+
+impl<B: ExtensibleField<3>> CoeffLayout<B> {
+    const IS_VALID: () = assert!(
+        mem::size_of::<CubeExtension<B>>() == 3 * mem::size_of::<B>()
+            && mem::align_of::<CubeExtension<B>>() == mem::align_of::<B>(),
+        "cubic extension element must be laid out as three consecutive base elements"
+    );
+}
+
+/// Forces evaluation of [CoeffLayout::IS_VALID] for B; this fails compilation for any B for which
+/// the layout is invalid.
+#[inline(always)]
+fn assert_coeff_layout<B: ExtensibleField<3>>() {
+    #[allow(clippy::let_unit_value)]
+    let _ = CoeffLayout::<B>::IS_VALID;
+}
+
+/// Checks at compile time that an array of `N` bytes can hold exactly one cubic extension element
+/// over B.
+struct ByteArrayLength<B, const N: usize>(PhantomData<B>);
+
+impl<B: FieldElement, const N: usize> ByteArrayLength<B, N> {
+    const IS_VALID: () = assert!(
+        N == B::ELEMENT_BYTES * 3,
+        "array length must be equal to the number of bytes in a cubic extension element"
+    );
+}
+
+/// Writes bytes into a fixed-size byte array.
+struct ArrayWriter<'a> {
+    target: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> ArrayWriter<'a> {
+    fn new(target: &'a mut [u8]) -> Self {
+        Self { target, pos: 0 }
+    }
+}
+
+impl ByteWriter for ArrayWriter<'_> {
+    fn write_u8(&mut self, value: u8) {
+        self.write_u8_slice(&[value]);
+    }
+
+    fn write_u8_slice(&mut self, values: &[u8]) {
+        let end = self.pos + values.len();
+        assert!(
+            end <= self.target.len(),
+            "not enough space in the target array"
+        );
+        self.target[self.pos..end].copy_from_slice(values);
+        self.pos = end;
+    }
+}
+
+/// Reads a base field element from the `source` and makes sure that it was encoded canonically;
+/// i.e., that re-encoding the element yields exactly the bytes which were read.
+fn read_canonical_base<B: StarkField, R: ByteReader>(
+    source: &mut R,
+) -> Result<B, DeserializationError> {
+    let bytes = source.read_u8_vec(B::ELEMENT_BYTES)?;
+    let value = B::read_from(&mut SliceReader::new(&bytes))?;
+    if value.to_bytes() != bytes {
+        return Err(DeserializationError::InvalidValue(format!(
+            "non-canonical encoding of base field element {:?}",
+            value.as_int()
+        )));
+    }
+    Ok(value)
+}
+
+/// Writes `value` into the `target` as an unsigned LEB128 varint.
+fn write_varint<W: ByteWriter>(mut value: u64, target: &mut W) {
+    while value >= 0x80 {
+        target.write_u8((value as u8) | 0x80);
+        value >>= 7;
+    }
+    target.write_u8(value as u8);
+}
+
+/// Reads an unsigned LEB128 varint from the `source`.
+fn read_varint<R: ByteReader>(source: &mut R) -> Result<u64, DeserializationError> {
+    let mut result = 0u64;
+    for i in 0..10 {
+        let byte = source.read_u8()?;
+        let bits = (byte & 0x7f) as u64;
+        // the 10th byte can contribute only a single bit to a 64-bit value
+        if i == 9 && bits > 1 {
+            return Err(DeserializationError::InvalidValue(
+                "varint value does not fit into 64 bits".to_string(),
+            ));
+        }
+        result |= bits << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err(DeserializationError::InvalidValue(
+        "varint encoding is longer than 10 bytes".to_string(),
+    ))
+}
+
+/// Returns the modulus of the base field as 64-bit limbs in little-endian order.
+fn modulus_limbs<B: StarkField>() -> Vec<u64> {
+    B::get_modulus_le_bytes()
+        .chunks(8)
+        .map(|chunk| {
+            let mut limb = [0u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect()
+}
+
+/// Returns the product of integers specified as 64-bit limbs in little-endian order.
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = (x as u128) * (y as u128) + (result[i + j] as u128) + carry;
+            result[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        result[i + b.len()] = carry as u64;
+    }
+    result
+}
+
+/// Divides an integer specified as 64-bit limbs in little-endian order by `d`, and returns the
+/// quotient (in the same representation) together with the remainder.
+fn div_rem_limbs(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut quotient = vec![0u64; a.len()];
+    let mut remainder = 0u128;
+    for (q, &limb) in quotient.iter_mut().zip(a.iter()).rev() {
+        let t = (remainder << 64) | (limb as u128);
+        *q = (t / d as u128) as u64;
+        remainder = t % d as u128;
+    }
+    (quotient, remainder as u64)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AsBytes, ByteReader, CubeExtension, Deserializable, DeserializationError, FieldElement,
+        QuadExtension, Randomizable, Serializable, SliceReader, Vec,
+    };
+    use crate::{
+        fft,
+        field::{
+            f128, f61, f62, f64::BaseElement, f7, traits::mul_with_irreducible, ExtensibleField,
+            ExtensionOf, StarkField,
+        },
+        polynom,
+    };
+    use core::{cmp::Ordering, convert::TryFrom, mem, ptr};
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+    use rand_utils::{rand_array, rand_value, rand_vector};
+
+    // BASIC ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn add() {
+        // identity
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r, r + CubeExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1: CubeExtension<BaseElement> = rand_value();
+        let r2: CubeExtension<BaseElement> = rand_value();
+
+        let expected = CubeExtension(r1.0 + r2.0, r1.1 + r2.1, r1.2 + r2.2);
+        assert_eq!(expected, r1 + r2);
+    }
+
+    #[test]
+    fn sub() {
+        // identity
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r, r - CubeExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1: CubeExtension<BaseElement> = rand_value();
+        let r2: CubeExtension<BaseElement> = rand_value();
+
+        let expected = CubeExtension(r1.0 - r2.0, r1.1 - r2.1, r1.2 - r2.2);
+        assert_eq!(expected, r1 - r2);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_ops() {
+        let r1: CubeExtension<BaseElement> = rand_value();
+        let r2: CubeExtension<BaseElement> = rand_value();
+
+        assert_eq!(r1 + r2, &r1 + &r2);
+        assert_eq!(r1 - r2, &r1 - &r2);
+        assert_eq!(r1 * r2, &r1 * &r2);
+        assert_eq!(r1 / r2, &r1 / &r2);
+        assert_eq!(-r1, -&r1);
+
+        let mut r = r1;
+        r += &r2;
+        assert_eq!(r1 + r2, r);
+        r -= &r2;
+        assert_eq!(r1, r);
+        r *= &r2;
+        assert_eq!(r1 * r2, r);
+        r /= &r2;
+        assert_eq!(r1, r);
+
+        // towers support reference operators too
+        let t1 = rand_tower_value();
+        let t2 = rand_tower_value();
+        assert_eq!(t1 + t2, &t1 + &t2);
+        assert_eq!(t1 - t2, &t1 - &t2);
+        assert_eq!(t1 * t2, &t1 * &t2);
+        assert_eq!(t1 / t2, &t1 / &t2);
+    }
+
+    #[test]
+    fn is_zero_and_is_one() {
+        let zero = CubeExtension::<BaseElement>::ZERO;
+        let one = CubeExtension::<BaseElement>::ONE;
+        assert!(zero.is_zero());
+        assert!(!zero.is_one());
+        assert!(one.is_one());
+        assert!(!one.is_zero());
+
+        // non-canonical representations of ZERO and ONE
+        let nc_zero = -BaseElement::ONE + BaseElement::ONE;
+        let nc_one = -BaseElement::ONE + BaseElement::new(2);
+        assert!(CubeExtension(nc_zero, nc_zero, nc_zero).is_zero());
+        assert!(CubeExtension(BaseElement::ONE, nc_zero, nc_zero).is_one());
+        assert!(CubeExtension(nc_one, BaseElement::ZERO, nc_zero).is_one());
+
+        // elements which differ from ZERO and ONE in a single coordinate
+        let r: BaseElement = rand_value();
+        for e in [
+            CubeExtension(r, BaseElement::ZERO, BaseElement::ZERO),
+            CubeExtension(BaseElement::ZERO, r, BaseElement::ZERO),
+            CubeExtension(BaseElement::ONE, BaseElement::ZERO, r),
+        ] {
+            assert_eq!(e == zero, e.is_zero());
+            assert_eq!(e == one, e.is_one());
+        }
+
+        for _ in 0..100 {
+            let e: CubeExtension<BaseElement> = rand_value();
+            assert_eq!(e == zero, e.is_zero());
+            assert_eq!(e == one, e.is_one());
+        }
+    }
+
+    #[test]
+    fn is_in_base_field() {
+        let b: BaseElement = rand_value();
+        let e = CubeExtension::<BaseElement>::from(b);
+        assert!(e.is_in_base_field());
+        assert_eq!(Some(b), e.to_base());
+
+        assert!(CubeExtension::<BaseElement>::ZERO.is_in_base_field());
+        assert!(CubeExtension::<BaseElement>::ONE.is_in_base_field());
+
+        let e: CubeExtension<BaseElement> = rand_value();
+        assert!(!e.is_in_base_field());
+        assert_eq!(None, e.to_base());
+
+        // a single non-zero higher coordinate is enough to leave the base field
+        let e = CubeExtension::new(b, BaseElement::ZERO, BaseElement::ONE);
+        assert!(!e.is_in_base_field());
+        let e = CubeExtension::new(b, BaseElement::ONE, BaseElement::ZERO);
+        assert_eq!(None, e.to_base());
+    }
+
+    #[test]
+    fn double_and_triple() {
+        let zero = CubeExtension::<BaseElement>::ZERO;
+        assert_eq!(zero, zero.double());
+        assert_eq!(zero, zero.triple());
+
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r + r, r.double());
+        assert_eq!(r + r + r, r.triple());
+
+        // values close to the modulus
+        let r = CubeExtension(-BaseElement::ONE, -BaseElement::new(2), BaseElement::ONE);
+        assert_eq!(r + r, r.double());
+        assert_eq!(r + r + r, r.triple());
+    }
+
+    #[test]
+    fn inv_checked() {
+        assert_eq!(None, CubeExtension::<BaseElement>::ZERO.inv_checked());
+
+        let x: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(
+            CubeExtension::<BaseElement>::ONE,
+            x.inv_checked().unwrap() * x
+        );
+        assert_eq!(Some(x.inv()), x.inv_checked());
+    }
+
+    #[test]
+    fn inv_matches_reference() {
+        // inversion via precomputed squared frobenius must match the conjugate-based reference
+        for _ in 0..100 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            let c1 = x.frobenius();
+            let c2 = c1.frobenius();
+            let norm = (x * c1 * c2).to_array()[0];
+            let expected = c1 * c2 * CubeExtension::from(norm.inv());
+            assert_eq!(expected, x.inv());
+            assert_eq!(CubeExtension::<BaseElement>::ONE, x * x.inv());
+        }
+
+        // towers fall back to the default frobenius_squared
+        let t = rand_tower_value();
+        assert_eq!(Tower::ONE, t * t.inv());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot divide by ZERO")]
+    fn div_by_zero() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        let _ = x / CubeExtension::<BaseElement>::ZERO;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "cannot divide by ZERO")]
+    fn div_assign_by_zero() {
+        let mut x: CubeExtension<BaseElement> = rand_value();
+        x /= CubeExtension::<BaseElement>::ZERO;
+    }
+
+    #[test]
+    fn checked_div() {
+        for _ in 0..100 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            let y: CubeExtension<BaseElement> = rand_value();
+            assert_eq!(Some(x / y), x.checked_div(y));
+            assert_eq!(x, x.checked_div(y).unwrap() * y);
+        }
+
+        let x: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(None, x.checked_div(CubeExtension::ZERO));
+        assert_eq!(
+            None,
+            CubeExtension::<BaseElement>::ZERO.checked_div(CubeExtension::ZERO)
+        );
+        assert_eq!(
+            Some(CubeExtension::ZERO),
+            CubeExtension::ZERO.checked_div(x)
+        );
+        assert_eq!(Some(CubeExtension::ONE), x.checked_div(x));
+
+        // ZERO with a non-canonical internal representation
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        assert_eq!(None, x.checked_div(CubeExtension(zero, zero, zero)));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+
+        let mut expected_sum = CubeExtension::<BaseElement>::ZERO;
+        let mut expected_product = CubeExtension::<BaseElement>::ONE;
+        for &value in values.iter() {
+            expected_sum += value;
+            expected_product *= value;
+        }
+
+        assert_eq!(expected_sum, values.iter().copied().sum());
+        assert_eq!(expected_sum, values.iter().sum());
+        assert_eq!(expected_product, values.iter().copied().product());
+        assert_eq!(expected_product, values.iter().product());
+
+        // empty iterators
+        let empty: Vec<CubeExtension<BaseElement>> = Vec::new();
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            empty.iter().copied().sum()
+        );
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, empty.iter().sum());
+        assert_eq!(
+            CubeExtension::<BaseElement>::ONE,
+            empty.iter().copied().product()
+        );
+        assert_eq!(CubeExtension::<BaseElement>::ONE, empty.iter().product());
+    }
+
+    #[test]
+    fn mul_slices() {
+        let a: Vec<CubeExtension<BaseElement>> = rand_vector(64);
+        let b: Vec<CubeExtension<BaseElement>> = rand_vector(64);
+
+        let mut expected = Vec::with_capacity(a.len());
+        for i in 0..a.len() {
+            expected.push(a[i] * b[i]);
+        }
+        assert_eq!(expected, CubeExtension::mul_slices(&a, &b));
+
+        let mut c = a.clone();
+        CubeExtension::mul_assign_slice(&mut c, &b);
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values must be the same for both slices")]
+    fn mul_slices_length_mismatch() {
+        let a: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let b: Vec<CubeExtension<BaseElement>> = rand_vector(3);
+        CubeExtension::mul_slices(&a, &b);
+    }
+
+    #[test]
+    fn linear_combination() {
+        let values: Vec<CubeExtension<BaseElement>> = rand_vector(64);
+        let scalars: Vec<BaseElement> = rand_vector(64);
+
+        let mut expected = CubeExtension::<BaseElement>::ZERO;
+        for i in 0..values.len() {
+            expected += values[i] * CubeExtension::from(scalars[i]);
+        }
+        assert_eq!(
+            expected,
+            CubeExtension::linear_combination(&values, &scalars)
+        );
+
+        let scalars: Vec<CubeExtension<BaseElement>> = rand_vector(64);
+        let mut expected = CubeExtension::<BaseElement>::ZERO;
+        for i in 0..values.len() {
+            expected += values[i] * scalars[i];
+        }
+        assert_eq!(
+            expected,
+            CubeExtension::linear_combination_ext(&values, &scalars)
+        );
+
+        // empty combinations are ZERO
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::linear_combination(&[], &[])
+        );
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::linear_combination_ext(&[], &[])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values must be the same for both slices")]
+    fn linear_combination_length_mismatch() {
+        let values: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let scalars: Vec<BaseElement> = rand_vector(3);
+        CubeExtension::linear_combination(&values, &scalars);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values must be the same for both slices")]
+    fn linear_combination_ext_length_mismatch() {
+        let values: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let scalars: Vec<CubeExtension<BaseElement>> = rand_vector(3);
+        CubeExtension::linear_combination_ext(&values, &scalars);
+    }
+
+    #[test]
+    fn inner_product() {
+        let a: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let b: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let c: Vec<BaseElement> = rand_vector(16);
+
+        let mut expected = CubeExtension::ZERO;
+        let mut expected_base = CubeExtension::ZERO;
+        for i in 0..a.len() {
+            expected += a[i] * b[i];
+            expected_base += a[i] * CubeExtension::from(c[i]);
+        }
+        assert_eq!(expected, CubeExtension::inner_product(&a, &b));
+        assert_eq!(expected_base, CubeExtension::inner_product_base(&a, &c));
+
+        // empty slices
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::inner_product(&[], &[])
+        );
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::inner_product_base(&[], &[])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values must be the same for both slices")]
+    fn inner_product_length_mismatch() {
+        let a: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let b: Vec<CubeExtension<BaseElement>> = rand_vector(5);
+        CubeExtension::inner_product(&a, &b);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values must be the same for both slices")]
+    fn inner_product_base_length_mismatch() {
+        let a: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let b: Vec<BaseElement> = rand_vector(5);
+        CubeExtension::inner_product_base(&a, &b);
+    }
+
+    #[test]
+    fn square() {
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::<BaseElement>::ZERO.square()
+        );
+        assert_eq!(
+            CubeExtension::<BaseElement>::ONE,
+            CubeExtension::<BaseElement>::ONE.square()
+        );
+
+        for _ in 0..1000 {
+            let r: CubeExtension<BaseElement> = rand_value();
+            assert_eq!(r * r, r.square());
+        }
+    }
+
+    #[test]
+    fn exp() {
+        let r: CubeExtension<BaseElement> = rand_value();
+
+        assert_eq!(CubeExtension::<BaseElement>::ONE, r.exp(0));
+        assert_eq!(r, r.exp(1));
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::<BaseElement>::ZERO.exp(5)
+        );
+        assert_eq!(
+            CubeExtension::<BaseElement>::ONE,
+            CubeExtension::<BaseElement>::ZERO.exp(0)
+        );
+
+        // small exponents are compared against repeated multiplication
+        let mut expected = CubeExtension::<BaseElement>::ONE;
+        for power in 0..100u64 {
+            assert_eq!(expected, r.exp(power));
+            expected *= r;
+        }
+
+        // large exponents are compared against plain square-and-multiply
+        for _ in 0..100 {
+            let power: u64 = rand_value();
+            assert_eq!(exp_reference(r, power), r.exp(power));
+        }
+        assert_eq!(exp_reference(r, u64::MAX), r.exp(u64::MAX));
+        assert_eq!(exp_reference(r, 1 << 32), r.exp(1 << 32));
+        assert_eq!(exp_reference(r, 1 << 63), r.exp(1 << 63));
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(
+            Some(CubeExtension::<BaseElement>::ZERO),
+            CubeExtension::<BaseElement>::ZERO.sqrt()
+        );
+
+        for _ in 0..10 {
+            let r: CubeExtension<BaseElement> = rand_value();
+            let square = r.square();
+            let root = square.sqrt().unwrap();
+            assert_eq!(square, root.square());
+            assert!(root == r || root == -r);
+        }
+
+        // generator of the base field is a non-residue in the cubic extension
+        let g = CubeExtension::<BaseElement>::from(BaseElement::GENERATOR);
+        assert_eq!(None, g.sqrt());
+        assert_eq!(None, (g * rand_value::<CubeExtension<BaseElement>>().square()).sqrt());
+    }
+
+    #[test]
+    fn frobenius() {
+        // frobenius automorphism of a cubic extension has order 3
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r, r.frobenius().frobenius().frobenius());
+        assert_eq!(r.conjugate(), r.frobenius());
+
+        // base field elements are fixed by the frobenius automorphism
+        let b: BaseElement = rand_value();
+        let r = CubeExtension::<BaseElement>::from(b);
+        assert_eq!(r, r.frobenius());
+    }
+
+    #[test]
+    fn debug_validate() {
+        assert_eq!(Ok(()), CubeExtension::<BaseElement>::debug_validate());
+        assert_eq!(Ok(()), CubeExtension::<f62::BaseElement>::debug_validate());
+        assert_eq!(Ok(()), CubeExtension::<f61::BaseElement>::debug_validate());
+        assert_eq!(Ok(()), CubeExtension::<f7::BaseElement>::debug_validate());
+
+        let err = CubeExtension::<f128::BaseElement>::debug_validate().unwrap_err();
+        assert_eq!("cubic extension is not supported by the base field", err);
+    }
+
+    #[test]
+    fn frobenius_k() {
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r, r.frobenius_k(0));
+        assert_eq!(r.conjugate(), r.frobenius_k(1));
+        assert_eq!(r.frobenius().frobenius(), r.frobenius_k(2));
+        assert_eq!(r, r.frobenius_k(3));
+        assert_eq!(r.conjugate(), r.frobenius_k(3001));
+        assert_eq!(r.conjugates()[2], r.frobenius_k(usize::MAX - 1));
+
+        // over the tower, the automorphism raises elements to the power p^2
+        let t = rand_tower_value();
+        assert_eq!(t, t.frobenius_k(3));
+        assert_eq!(t.frobenius().frobenius(), t.frobenius_k(5));
+
+        // frobenius_k(k) is the same as raising to the power 7^k in the toy field
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            assert_eq!(x.exp(7), x.frobenius_k(1));
+            assert_eq!(x.exp(49), x.frobenius_k(2));
+            assert_eq!(x, x.frobenius_k(3));
+        }
+    }
+
+    #[test]
+    fn conjugates() {
+        let r: CubeExtension<BaseElement> = rand_value();
+        let [c0, c1, c2] = r.conjugates();
+        assert_eq!(r, c0);
+        assert_eq!(r.frobenius(), c1);
+        assert_eq!(r.frobenius().frobenius(), c2);
+
+        // product of the conjugates is the norm, and the sum is the trace
+        assert_eq!(CubeExtension::from(r.norm()), c0 * c1 * c2);
+        assert_eq!(CubeExtension::from(r.trace()), c0 + c1 + c2);
+
+        // conjugates of a base field element are all the same
+        let b = CubeExtension::<BaseElement>::from(rand_value::<BaseElement>());
+        assert_eq!([b, b, b], b.conjugates());
+
+        // the same identities hold for towers (relative to the quadratic extension)
+        let t = rand_tower_value();
+        let [c0, c1, c2] = t.conjugates();
+        assert_eq!(Tower::from(t.norm()), c0 * c1 * c2);
+        assert_eq!(Tower::from(t.trace()), c0 + c1 + c2);
+    }
+
+    #[test]
+    fn norm() {
+        // norm is multiplicative
+        let r1: CubeExtension<BaseElement> = rand_value();
+        let r2: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r1.norm() * r2.norm(), (r1 * r2).norm());
+
+        // norm of a base field element b is b^3
+        let b: BaseElement = rand_value();
+        assert_eq!(b.cube(), CubeExtension::<BaseElement>::from(b).norm());
+
+        assert_eq!(BaseElement::ZERO, CubeExtension::<BaseElement>::ZERO.norm());
+        assert_eq!(BaseElement::ONE, CubeExtension::<BaseElement>::ONE.norm());
+    }
+
+    #[test]
+    fn trace() {
+        // trace is additive
+        let r1: CubeExtension<BaseElement> = rand_value();
+        let r2: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r1.trace() + r2.trace(), (r1 + r2).trace());
+
+        // trace of a base field element b is 3 * b
+        let b: BaseElement = rand_value();
+        assert_eq!(
+            b * BaseElement::from(3u8),
+            CubeExtension::<BaseElement>::from(b).trace()
+        );
+    }
+
+    #[test]
+    fn batch_inv() {
+        let mut elements: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        elements[3] = CubeExtension::<BaseElement>::ZERO;
+        elements[11] = CubeExtension::<BaseElement>::ZERO;
+
+        let result = CubeExtension::<BaseElement>::batch_inv(&elements);
+        assert_eq!(elements.len(), result.len());
+        for (&element, &inverse) in elements.iter().zip(result.iter()) {
+            assert_eq!(element.inv(), inverse);
+        }
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, result[3]);
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, result[11]);
+    }
+
+    #[test]
+    fn try_batch_inv() {
+        let mut elements: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let result = CubeExtension::try_batch_inv(&elements).unwrap();
+        assert_eq!(CubeExtension::batch_inv(&elements), result);
+
+        // the index of the first ZERO is reported, including non-canonical ZEROs
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        elements[2] = CubeExtension(zero, zero, zero);
+        elements[7] = CubeExtension::ZERO;
+        assert_eq!(Err(2), CubeExtension::try_batch_inv(&elements));
+
+        // an empty slice is trivially invertible
+        assert_eq!(
+            Ok(Vec::new()),
+            CubeExtension::<BaseElement>::try_batch_inv(&[])
+        );
+    }
+
+    #[test]
+    fn batch_inv_with_norms() {
+        let mut elements: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        elements[5] = CubeExtension::<BaseElement>::ZERO;
+
+        let (inverses, norms) = CubeExtension::<BaseElement>::batch_inv_with_norms(&elements);
+        assert_eq!(elements.len(), inverses.len());
+        assert_eq!(elements.len(), norms.len());
+        for i in 0..elements.len() {
+            assert_eq!(elements[i].norm(), norms[i]);
+            assert_eq!(elements[i].inv(), inverses[i]);
+        }
+        assert_eq!(BaseElement::ZERO, norms[5]);
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, inverses[5]);
+        assert_eq!(CubeExtension::<BaseElement>::ONE, elements[0] * inverses[0]);
+
+        let (inverses, norms) = CubeExtension::<BaseElement>::batch_inv_with_norms(&[]);
+        assert!(inverses.is_empty());
+        assert!(norms.is_empty());
+    }
+
+    #[test]
+    fn permute_bit_reversed() {
+        let source: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+
+        // permuting twice is the identity
+        let mut elements = source.clone();
+        CubeExtension::permute_bit_reversed(&mut elements);
+        assert_ne!(source, elements);
+        CubeExtension::unpermute_bit_reversed(&mut elements);
+        assert_eq!(source, elements);
+
+        // coordinates stay grouped; i.e., permuting every coordinate column (base elements with
+        // stride 3) in the base field gives the same result
+        let mut elements = source.clone();
+        CubeExtension::permute_bit_reversed(&mut elements);
+        let base = CubeExtension::<BaseElement>::as_base_elements(&source);
+        let mut columns: Vec<Vec<BaseElement>> = (0..3)
+            .map(|k| base.iter().skip(k).step_by(3).copied().collect())
+            .collect();
+        for column in columns.iter_mut() {
+            fft::permute(column);
+        }
+        let expected: Vec<CubeExtension<BaseElement>> = (0..source.len())
+            .map(|i| CubeExtension(columns[0][i], columns[1][i], columns[2][i]))
+            .collect();
+        assert_eq!(expected, elements);
+
+        // trivial slices are left unchanged
+        let mut empty: Vec<CubeExtension<BaseElement>> = Vec::new();
+        CubeExtension::permute_bit_reversed(&mut empty);
+        assert!(empty.is_empty());
+        let mut single = source[..1].to_vec();
+        CubeExtension::permute_bit_reversed(&mut single);
+        assert_eq!(source[..1], single);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of elements must be a power of two, but was 5")]
+    fn permute_bit_reversed_invalid_length() {
+        let mut elements: Vec<CubeExtension<BaseElement>> = rand_vector(5);
+        CubeExtension::permute_bit_reversed(&mut elements);
+    }
+
+    #[test]
+    fn mul_base() {
+        for _ in 0..100 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            let b: BaseElement = rand_value();
+            let expected = x * CubeExtension::from(b);
+            assert_eq!(expected, x.mul_base(b));
+            assert_eq!(expected, x * b);
+
+            let mut y = x;
+            y *= b;
+            assert_eq!(x.mul_base(b), y);
+
+            // the field element hook must agree with the inherent method
+            assert_eq!(expected, FieldElement::mul_base(x, b));
+        }
+
+        // for the tower, the hook lifts base field elements into the quadratic extension; other
+        // extensions use the default implementation
+        let x = rand_tower_value();
+        let b: BaseElement = rand_value();
+        assert_eq!(x * CubeExtension::from(b), FieldElement::mul_base(x, b));
+        let x: QuadExtension<BaseElement> = rand_value();
+        assert_eq!(x * QuadExtension::from(b), FieldElement::mul_base(x, b));
+
+        let x: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, x * BaseElement::ZERO);
+        assert_eq!(x, x * BaseElement::ONE);
+    }
+
+    #[test]
+    fn mul_assign_base() {
+        // in-place multiplication must go through the same hook as mul_base()
+        fn check<B: ExtensibleField<3>>() {
+            for _ in 0..100 {
+                let x = CubeExtension::<B>::from_array(rand_array());
+                let b: B = rand_value();
+                let mut y = x;
+                y *= b;
+                assert_eq!(x.mul_base(b), y);
+            }
+        }
+        check::<BaseElement>();
+        check::<f62::BaseElement>();
+        check::<f61::BaseElement>();
+        check::<QuadExtension<BaseElement>>();
+
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            for b in 0..7u64 {
+                let b = f7::BaseElement::new(b);
+                let mut y = x;
+                y *= b;
+                assert_eq!(x.mul_base(b), y);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_base_default() {
+        // the default implementation must be the same as a full multiplication by a lifted scalar
+        check_mul_base::<BaseElement>();
+        check_mul_base::<f62::BaseElement>();
+        check_mul_base::<f61::BaseElement>();
+        check_mul_base::<QuadExtension<BaseElement>>();
+        for x in CubeExtension::<f7::BaseElement>::all_elements().step_by(5) {
+            for b in 0..7u64 {
+                let b = f7::BaseElement::new(b);
+                assert_eq!(x * CubeExtension::from(b), x.mul_base(b));
+            }
+        }
+
+        // the default implementation is generic over the extension degree
+        let a: [BaseElement; 2] = rand_array();
+        let b: BaseElement = rand_value();
+        let expected = <BaseElement as ExtensibleField<2>>::mul(a, [b, BaseElement::ZERO]);
+        assert_eq!(
+            expected,
+            <BaseElement as ExtensibleField<2>>::mul_base(a, b)
+        );
+    }
+
+    #[test]
+    fn add_sub_base() {
+        for _ in 0..100 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            let b: BaseElement = rand_value();
+
+            let expected = x + CubeExtension::from(b);
+            assert_eq!(expected, x.add_base(b));
+            assert_eq!(expected, x + b);
+
+            let expected = x - CubeExtension::from(b);
+            assert_eq!(expected, x.sub_base(b));
+            assert_eq!(expected, x - b);
+
+            assert_eq!(x, x.add_base(b).sub_base(b));
+        }
+
+        // the base element may wrap around the modulus in the first coordinate only
+        let x = CubeExtension::new(
+            BaseElement::new(1),
+            BaseElement::new(2),
+            BaseElement::new(3),
+        );
+        let expected = CubeExtension::new(
+            BaseElement::new(2),
+            BaseElement::new(2),
+            BaseElement::new(3),
+        );
+        assert_eq!(expected, x.sub_base(-BaseElement::ONE));
+        let expected =
+            CubeExtension::new(BaseElement::ZERO, BaseElement::new(2), BaseElement::new(3));
+        assert_eq!(expected, x.add_base(-BaseElement::ONE));
+
+        let t: Tower = rand_tower_value();
+        let b: QuadExtension<BaseElement> = rand_value();
+        assert_eq!(t + Tower::from(b), t + b);
+        assert_eq!(t - Tower::from(b), t - b);
+    }
+
+    #[test]
+    fn negate_assign() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        let mut y = x;
+        y.negate_assign();
+        assert_eq!(-x, y);
+
+        let mut z = CubeExtension::<BaseElement>::ZERO;
+        z.negate_assign();
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, z);
+    }
+
+    #[test]
+    fn neg_assign() {
+        for _ in 0..100 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            let mut y = x;
+            y.neg_assign();
+            assert_eq!(-x, y);
+        }
+
+        let mut z = CubeExtension::<BaseElement>::ZERO;
+        z.neg_assign();
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, z);
+
+        let mut values: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let expected = values.iter().map(|&x| -x).collect::<Vec<_>>();
+        values.iter_mut().for_each(|x| x.neg_assign());
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn sub_from() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        let y: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(y - x, x.sub_from(y));
+        assert_eq!(-x, x.sub_from(CubeExtension::ZERO));
+        assert_eq!(CubeExtension::ZERO, x.sub_from(x));
+    }
+
+    #[test]
+    fn negate_slice() {
+        let values: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let mut result = values.clone();
+        CubeExtension::negate_slice(&mut result);
+        for (&x, &y) in values.iter().zip(result.iter()) {
+            assert_eq!(-x, y);
+        }
+
+        let mut empty: Vec<CubeExtension<BaseElement>> = Vec::new();
+        CubeExtension::negate_slice(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn invert_assign() {
+        for _ in 0..100 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            let mut y = x;
+            y.invert_assign();
+            assert_eq!(x.inv(), y);
+        }
+
+        let mut z = CubeExtension::<BaseElement>::ZERO;
+        z.invert_assign();
+        assert_eq!(CubeExtension::<BaseElement>::ZERO, z);
+
+        let mut t = rand_tower_value();
+        let expected = t.inv();
+        t.invert_assign();
+        assert_eq!(expected, t);
+    }
+
+    #[test]
+    fn eval_many() {
+        let coeffs: Vec<CubeExtension<BaseElement>> = rand_vector(32);
+        let points: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+
+        let expected = points
+            .iter()
+            .map(|&x| polynom::eval(&coeffs, x))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, CubeExtension::eval_many(&coeffs, &points));
+
+        // empty coefficients evaluate to ZERO at every point
+        let result = CubeExtension::eval_many(&[], &points);
+        assert_eq!(
+            vec![CubeExtension::<BaseElement>::ZERO; points.len()],
+            result
+        );
+
+        // empty points result in an empty output
+        assert!(CubeExtension::eval_many(&coeffs, &[]).is_empty());
+    }
+
+    #[test]
+    fn interpolate_from_base_domain() {
+        let domain: Vec<BaseElement> = rand_vector(16);
+        let evals: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+
+        let poly = CubeExtension::interpolate_from_base_domain(&domain, &evals);
+        assert_eq!(domain.len(), poly.len());
+        for (&x, &y) in domain.iter().zip(evals.iter()) {
+            assert_eq!(y, polynom::eval(&poly, CubeExtension::from(x)));
+        }
+
+        // the result is the same as interpolating over the lifted domain
+        let lifted = domain
+            .iter()
+            .map(|&x| CubeExtension::from(x))
+            .collect::<Vec<_>>();
+        assert_eq!(polynom::interpolate(&lifted, &evals, false), poly);
+
+        // a polynomial of low degree is recovered exactly
+        let coeffs: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let evals = domain
+            .iter()
+            .map(|&x| polynom::eval(&coeffs, CubeExtension::from(x)))
+            .collect::<Vec<_>>();
+        let poly = CubeExtension::interpolate_from_base_domain(&domain, &evals);
+        assert_eq!(coeffs, polynom::remove_leading_zeros(&poly));
+
+        // empty domain produces an empty polynomial
+        assert!(CubeExtension::<BaseElement>::interpolate_from_base_domain(&[], &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "number of domain points and evaluations must be the same")]
+    fn interpolate_from_base_domain_length_mismatch() {
+        let domain: Vec<BaseElement> = rand_vector(4);
+        let evals: Vec<CubeExtension<BaseElement>> = rand_vector(3);
+        CubeExtension::interpolate_from_base_domain(&domain, &evals);
+    }
+
+    #[test]
+    fn evaluate_vanishing() {
+        // coset of size 8 in the f64 field
+        let offset = BaseElement::GENERATOR;
+        let g = BaseElement::get_root_of_unity(3);
+        let domain = (0..8)
+            .map(|i| offset * g.exp(i))
+            .collect::<Vec<BaseElement>>();
+        for _ in 0..10 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            assert_eq!(
+                vanishing_reference(x, &domain),
+                CubeExtension::evaluate_vanishing(x, domain.len(), offset)
+            );
+        }
+
+        // the polynomial vanishes on the domain, but not outside of it
+        for &d in &domain {
+            let x = CubeExtension::from(d);
+            assert_eq!(
+                CubeExtension::ZERO,
+                CubeExtension::evaluate_vanishing(x, 8, offset)
+            );
+            let x = CubeExtension::from(d * BaseElement::GENERATOR);
+            assert_ne!(
+                CubeExtension::ZERO,
+                CubeExtension::evaluate_vanishing(x, 8, offset)
+            );
+        }
+
+        // domains of size which is not a power of two; in the toy field with modulus 7, the
+        // subgroup of size 6 consists of all non-zero elements, and 2 generates a subgroup of size 3
+        let field = (1..7).map(f7::BaseElement::new).collect::<Vec<_>>();
+        let subgroup = [1, 2, 4]
+            .iter()
+            .map(|&v| f7::BaseElement::new(v))
+            .collect::<Vec<_>>();
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            assert_eq!(
+                vanishing_reference(x, &field),
+                CubeExtension::evaluate_vanishing(x, 6, f7::BaseElement::ONE)
+            );
+            let offset = f7::BaseElement::new(3);
+            let coset = subgroup.iter().map(|&v| v * offset).collect::<Vec<_>>();
+            assert_eq!(
+                vanishing_reference(x, &coset),
+                CubeExtension::evaluate_vanishing(x, 3, offset)
+            );
+        }
+    }
+
+    #[test]
+    fn divide_by_linear() {
+        let z: CubeExtension<BaseElement> = rand_value();
+        let divisor = [-z, CubeExtension::ONE];
+
+        // multiplying the quotient back by (x - z) recovers the input
+        let quotient: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let poly = polynom::mul(&quotient, &divisor);
+        assert_eq!(quotient, CubeExtension::divide_by_linear(&poly, z));
+
+        // (f(x) - f(z)) / (x - z)
+        let mut poly: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let value = polynom::eval(&poly, z);
+        poly[0] -= value;
+        let quotient = CubeExtension::divide_by_linear(&poly, z);
+        assert_eq!(15, quotient.len());
+        assert_eq!(poly, polynom::mul(&quotient, &divisor));
+
+        // z = ZERO shifts the coefficients
+        let poly = polynom::mul(&quotient, &[CubeExtension::ZERO, CubeExtension::ONE]);
+        assert_eq!(
+            quotient,
+            CubeExtension::divide_by_linear(&poly, CubeExtension::ZERO)
+        );
+
+        // constant and empty polynomials yield empty quotients
+        assert!(CubeExtension::divide_by_linear(&[CubeExtension::ZERO], z).is_empty());
+        assert!(CubeExtension::<BaseElement>::divide_by_linear(&[], z).is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "polynomial is not divisible by (x - z)")]
+    fn divide_by_linear_not_root() {
+        let poly: Vec<CubeExtension<BaseElement>> = vec![CubeExtension::ONE, CubeExtension::ONE];
+        CubeExtension::divide_by_linear(&poly, CubeExtension::ONE);
+    }
+
+    #[test]
+    fn powers() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        let expected = (0..64u64).map(|i| x.exp(i)).collect::<Vec<_>>();
+
+        let actual = x.powers().take(64).collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+        assert_eq!(expected, x.powers_n(64));
+        assert!(x.powers_n(0).is_empty());
+    }
+
+    #[test]
+    fn eval_base_poly_at() {
+        let x = rand_value::<CubeExtension<BaseElement>>();
+
+        // empty polynomial evaluates to ZERO
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::eval_base_poly_at(&[], x)
+        );
+
+        // result must match evaluation of the polynomial with coefficients lifted into the
+        // extension field
+        let coeffs = rand_vector::<BaseElement>(64);
+        let lifted = coeffs
+            .iter()
+            .map(|&c| CubeExtension::<BaseElement>::from(c))
+            .collect::<Vec<_>>();
+        let expected = polynom::eval(&lifted, x);
+        assert_eq!(expected, CubeExtension::eval_base_poly_at(&coeffs, x));
+        assert_eq!(expected, polynom::eval(&coeffs, x));
+    }
+
+    // CONSTANT-TIME OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn select() {
+        let a: CubeExtension<BaseElement> = rand_value();
+        let b: CubeExtension<BaseElement> = rand_value();
+
+        for choice in [false, true] {
+            let expected = if choice { b } else { a };
+            assert_eq!(expected, CubeExtension::select(a, b, choice));
+        }
+
+        // the toy field relies on the default implementation of StarkField::select()
+        let elements = CubeExtension::<f7::BaseElement>::all_elements().collect::<Vec<_>>();
+        for (&a, &b) in elements.iter().zip(elements.iter().rev()) {
+            assert_eq!(a, CubeExtension::select(a, b, false));
+            assert_eq!(b, CubeExtension::select(a, b, true));
+        }
+    }
+
+    #[test]
+    fn ct_eq() {
+        // random elements
+        for _ in 0..100 {
+            let a: CubeExtension<BaseElement> = rand_value();
+            let b: CubeExtension<BaseElement> = rand_value();
+            assert_eq!(a == b, a.ct_eq(&b));
+            assert!(a.ct_eq(&a));
+        }
+
+        // elements which differ in a single coordinate
+        let a: CubeExtension<BaseElement> = rand_value();
+        for i in 0..3 {
+            let mut coords = a.to_array();
+            coords[i] += BaseElement::ONE;
+            let b = CubeExtension::from_array(coords);
+            assert!(!a.ct_eq(&b));
+            assert!(!b.ct_eq(&a));
+        }
+
+        // equal elements with different internal representations
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        let a = CubeExtension(zero, BaseElement::ONE, zero);
+        let b = CubeExtension(BaseElement::ZERO, BaseElement::ONE, BaseElement::ZERO);
+        assert!(a.ct_eq(&b));
+
+        // the toy field relies on the default implementation, which is checked exhaustively
+        let elements: Vec<_> = CubeExtension::<f7::BaseElement>::all_elements().collect();
+        for a in elements.iter() {
+            for b in elements.iter() {
+                assert_eq!(a == b, a.ct_eq(b));
+            }
+        }
+    }
+
+    #[test]
+    fn eval_bit_reversed() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        for n in [1, 2, 4, 8, 32, 256] {
+            let coeffs: Vec<CubeExtension<BaseElement>> = rand_vector(n);
+            let expected = polynom::eval(&coeffs, x);
+
+            let mut permuted = coeffs.clone();
+            CubeExtension::permute_bit_reversed(&mut permuted);
+            assert_eq!(expected, CubeExtension::eval_bit_reversed(&permuted, x));
+        }
+
+        assert_eq!(
+            CubeExtension::ZERO,
+            CubeExtension::<BaseElement>::eval_bit_reversed(&[], x)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "number of coefficients must be a power of two, but was 3")]
+    fn eval_bit_reversed_wrong_length() {
+        let coeffs: Vec<CubeExtension<BaseElement>> = rand_vector(3);
+        CubeExtension::eval_bit_reversed(&coeffs, rand_value());
+    }
+
+    #[test]
+    fn to_bits_from_bits() {
+        check_bits_round_trip::<BaseElement>();
+        check_bits_round_trip::<f62::BaseElement>();
+        check_bits_round_trip::<f61::BaseElement>();
+
+        // each coordinate of an f7 element is decomposed into 3 little-endian bits
+        let a = CubeExtension::<f7::BaseElement>::from_ints(1, 6, 4);
+        let expected = [true, false, false, false, true, true, false, false, true];
+        assert_eq!(expected.to_vec(), a.to_bits());
+        assert_eq!(a, CubeExtension::from_bits(&expected).unwrap());
+
+        // a non-canonical ZERO is decomposed the same way as a canonical one
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        let a = CubeExtension(zero, BaseElement::ONE, zero);
+        let b = CubeExtension(BaseElement::ZERO, BaseElement::ONE, BaseElement::ZERO);
+        assert_eq!(b.to_bits(), a.to_bits());
+    }
+
+    #[test]
+    fn from_bits_invalid() {
+        type E = CubeExtension<BaseElement>;
+        let num_bits = 3 * BaseElement::MODULUS_BITS as usize;
+
+        // wrong number of bits
+        for len in [0, num_bits - 1, num_bits + 1] {
+            let err = E::from_bits(&vec![false; len]).unwrap_err();
+            assert_eq!(
+                DeserializationError::InvalidValue(format!(
+                    "expected {} bits for a field element, but was {} bits",
+                    num_bits, len
+                )),
+                err
+            );
+        }
+
+        // a coordinate with all bits set encodes 2^64 - 1, which is not a valid field element
+        let mut bits = vec![false; num_bits];
+        bits[64..128].iter_mut().for_each(|bit| *bit = true);
+        assert!(E::from_bits(&bits).is_err());
+
+        // 6 = 0b110 is a valid f7 element, but 7 = 0b111 is not
+        let mut bits = [false; 9];
+        bits[7] = true;
+        bits[8] = true;
+        let a = CubeExtension::<f7::BaseElement>::from_bits(&bits).unwrap();
+        assert_eq!(CubeExtension::from_ints(0, 0, 6), a);
+        bits[6] = true;
+        assert!(CubeExtension::<f7::BaseElement>::from_bits(&bits).is_err());
+    }
+
+    #[test]
+    fn pow_2k() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(x, x.pow_2k(0));
+        for k in 1..64 {
+            assert_eq!(x.exp(1 << k), x.pow_2k(k));
+        }
+
+        // exponents which do not fit into an integer type
+        assert_eq!(x.pow_2k(100).pow_2k(200), x.pow_2k(300));
+        assert_eq!(
+            CubeExtension::ONE,
+            CubeExtension::<BaseElement>::ONE.pow_2k(1000)
+        );
+        assert_eq!(
+            CubeExtension::ZERO,
+            CubeExtension::<BaseElement>::ZERO.pow_2k(1000)
+        );
+
+        // x^(7^3 - 1) = 1 for all non-zero elements of the toy field; since 2^19 = 2 mod 342,
+        // squaring 19 times is the same as squaring once
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            assert_eq!(x.square(), x.pow_2k(19));
+        }
+    }
+
+    #[test]
+    fn order_divides() {
+        // compute the order of every element of the toy field by brute force
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            let order = if x == CubeExtension::ZERO {
+                None
+            } else {
+                let mut order = 1;
+                let mut y = x;
+                while y != CubeExtension::ONE {
+                    y *= x;
+                    order += 1;
+                }
+                Some(order)
+            };
+
+            for k in 0..700 {
+                let expected = match order {
+                    Some(order) => k % order == 0,
+                    None => k == 0,
+                };
+                assert_eq!(expected, x.order_divides(k), "x = {}, k = {}", x, k);
+            }
+        }
+
+        // p^3 - 1 does not fit into u64, but the order of elements in the base field divides p - 1
+        let x = CubeExtension::<BaseElement>::from(rand_value::<BaseElement>());
+        assert!(x.order_divides(BaseElement::MODULUS - 1));
+        assert!(CubeExtension::<BaseElement>::ONE.order_divides(rand_value()));
+    }
+
+    #[test]
+    fn is_nth_power() {
+        // check the result against the set of n-th powers of all elements of the toy field; this
+        // includes values of n which do not divide 7^3 - 1 = 342
+        let elements = CubeExtension::<f7::BaseElement>::all_elements().collect::<Vec<_>>();
+        for n in 1..=40 {
+            let powers = elements.iter().map(|x| x.exp(n)).collect::<Vec<_>>();
+            for x in elements.iter() {
+                assert_eq!(
+                    powers.contains(x),
+                    x.is_nth_power(n),
+                    "x = {}, n = {}",
+                    x,
+                    n
+                );
+            }
+        }
+        assert!(elements.iter().all(|x| x.is_nth_power(1)));
+        assert!(elements.iter().all(|x| x.is_nth_power(343)));
+
+        // over f64, squares are exactly the elements for which a square root exists
+        for _ in 0..20 {
+            let x: CubeExtension<BaseElement> = rand_value();
+            assert!(x.square().is_nth_power(2));
+            assert!(x.exp(3).is_nth_power(3));
+            assert_eq!(x.sqrt().is_some(), x.is_nth_power(2));
+        }
+
+        // the generator is not an n-th power for any non-trivial divisor n of p^3 - 1, but every
+        // element is an n-th power when n is coprime with p^3 - 1
+        let g = CubeExtension::<BaseElement>::multiplicative_generator();
+        assert!(!g.is_nth_power(2));
+        assert!(!g.is_nth_power(3));
+        assert!(!g.is_nth_power(5));
+        assert!(g.is_nth_power(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "n must be greater than zero")]
+    fn is_nth_power_zero() {
+        CubeExtension::<BaseElement>::ONE.is_nth_power(0);
+    }
+
+    #[test]
+    fn inv_ct() {
+        for _ in 0..100 {
+            let a: CubeExtension<BaseElement> = rand_value();
+            assert_eq!(a.inv(), a.inv_ct());
+        }
+
+        // ZERO, including a non-canonical representation of ZERO
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        assert_eq!(
+            CubeExtension::ZERO,
+            CubeExtension::<BaseElement>::ZERO.inv()
+        );
+        assert_eq!(
+            CubeExtension::ZERO,
+            CubeExtension::<BaseElement>::ZERO.inv_ct()
+        );
+        assert_eq!(
+            CubeExtension::ZERO,
+            CubeExtension(zero, zero, zero).inv_ct()
+        );
+
+        // exhaustively over the toy field
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            assert_eq!(x.inv(), x.inv_ct());
+        }
+    }
+
+    #[test]
+    fn cond_add() {
+        let values: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        let bits: u64 = rand_value();
+        let flags: Vec<bool> = (0..16).map(|i| (bits >> i) & 1 == 1).collect();
+
+        let mut acc = CubeExtension::<BaseElement>::ONE;
+        let mut expected = acc;
+        for (&value, &flag) in values.iter().zip(flags.iter()) {
+            acc.cond_add(value, flag);
+            if flag {
+                expected += value;
+            }
+        }
+        assert_eq!(expected, acc);
+
+        let x: CubeExtension<f62::BaseElement> = rand_value();
+        let y: CubeExtension<f62::BaseElement> = rand_value();
+        let mut z = x;
+        z.cond_add(y, false);
+        assert_eq!(x, z);
+        z.cond_add(y, true);
+        assert_eq!(x + y, z);
+    }
+
+    #[test]
+    fn normalize() {
+        // ZERO produced via arithmetic has a non-canonical internal representation in f64
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        let a = CubeExtension(zero, BaseElement::ONE, zero);
+        let b = CubeExtension(BaseElement::ZERO, BaseElement::ONE, BaseElement::ZERO);
+        assert_eq!(a, b);
+        assert_ne!(a.as_bytes(), b.as_bytes());
+        assert_eq!(a.normalize().as_bytes(), b.normalize().as_bytes());
+        assert_eq!(a, a.normalize());
+
+        // elements computed along different arithmetic paths
+        for _ in 0..100 {
+            let x: CubeExtension<f62::BaseElement> = rand_value();
+            let y: CubeExtension<f62::BaseElement> = rand_value();
+            let a = (x + y) * y - y * y;
+            let b = x * y;
+            assert_eq!(a, b);
+            assert_eq!(a.normalize().as_bytes(), b.normalize().as_bytes());
+        }
+
+        // normalization is a no-op for canonical fields
+        let x: CubeExtension<f61::BaseElement> = rand_value();
+        assert_eq!(x.as_bytes(), x.normalize().as_bytes());
+
+        // slices
+        let mut values = vec![a, b, a];
+        CubeExtension::normalize_slice(&mut values);
+        for value in values.iter() {
+            assert_eq!(value.as_bytes(), b.as_bytes());
+        }
+    }
+
+    // INITIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn zeroed_vector() {
+        let result = CubeExtension::<BaseElement>::zeroed_vector(4);
+        assert_eq!(4, result.len());
+        for element in result.into_iter() {
+            assert_eq!(CubeExtension::<BaseElement>::ZERO, element);
+        }
+    }
+
+    #[test]
+    fn rand_vector_coordinates() {
+        use std::collections::HashSet;
+
+        // random extension elements are built from random bytes via the Randomizable trait;
+        // make sure all three coordinates are populated independently
+        let n = 1000;
+        let result: Vec<CubeExtension<BaseElement>> = rand_vector(n);
+        assert_eq!(n, result.len());
+
+        let c0 = result.iter().map(|e| e.0.as_int()).collect::<HashSet<_>>();
+        let c1 = result.iter().map(|e| e.1.as_int()).collect::<HashSet<_>>();
+        let c2 = result.iter().map(|e| e.2.as_int()).collect::<HashSet<_>>();
+        assert!(c0.len() > n - 10);
+        assert!(c1.len() > n - 10);
+        assert!(c2.len() > n - 10);
+    }
+
+    #[test]
+    fn batch_from_random_bytes() {
+        let value_size = <CubeExtension<BaseElement> as Randomizable>::VALUE_SIZE;
+        let elements: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let bytes = CubeExtension::<BaseElement>::elements_as_bytes(&elements).to_vec();
+
+        // exact length
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes, 4);
+        assert_eq!(Some(elements.clone()), result);
+
+        // extra bytes are ignored
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes, 3);
+        assert_eq!(Some(elements[..3].to_vec()), result);
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes, 0);
+        assert_eq!(Some(Vec::new()), result);
+
+        // too short
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes[..95], 4);
+        assert_eq!(None, result);
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes, usize::MAX);
+        assert_eq!(None, result);
+
+        // a chunk which is rejected by the base field
+        let mut bytes = bytes;
+        bytes[2 * value_size..2 * value_size + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes, 4);
+        assert_eq!(None, result);
+        let result = CubeExtension::<BaseElement>::batch_from_random_bytes(&bytes, 2);
+        assert_eq!(Some(elements[..2].to_vec()), result);
+    }
+
+    #[test]
+    fn from_random_bytes_bounded() {
+        let value_size = <CubeExtension<BaseElement> as Randomizable>::VALUE_SIZE;
+        let elements: Vec<CubeExtension<BaseElement>> = rand_vector(3);
+        let mut bytes = CubeExtension::<BaseElement>::elements_as_bytes(&elements).to_vec();
+
+        // the first chunk is accepted
+        let result = CubeExtension::<BaseElement>::from_random_bytes_bounded(&bytes, 1);
+        assert_eq!(Some(elements[0]), result);
+
+        // the first chunk is rejected by the base field, and thus, two tries are needed
+        bytes[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+        let result = CubeExtension::<BaseElement>::from_random_bytes_bounded(&bytes, 1);
+        assert_eq!(None, result);
+        let result = CubeExtension::<BaseElement>::from_random_bytes_bounded(&bytes, 2);
+        assert_eq!(Some(elements[1]), result);
+        let result = CubeExtension::<BaseElement>::from_random_bytes_bounded(&bytes, 100);
+        assert_eq!(Some(elements[1]), result);
+
+        // not enough bytes for the second try
+        let result = CubeExtension::<BaseElement>::from_random_bytes_bounded(
+            &bytes[..2 * value_size - 1],
+            2,
+        );
+        assert_eq!(None, result);
+
+        // no tries
+        let result = CubeExtension::<BaseElement>::from_random_bytes_bounded(&bytes, 0);
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn from_random_bytes_matches_try_from() {
+        // for base fields which reject out-of-range values, drawing each coordinate separately
+        // must produce the same elements as deserializing the whole buffer
+        fn check<B: StarkField + ExtensibleField<3>>() {
+            let value_size = <CubeExtension<B> as Randomizable>::VALUE_SIZE;
+            for i in 0..100 {
+                let mut bytes: Vec<u8> = rand_vector::<u64>(value_size / 8)
+                    .iter()
+                    .flat_map(|v| v.to_le_bytes())
+                    .collect();
+                // make some coordinates out of range
+                if i % 4 == 0 {
+                    let chunk = (i / 4) % 3;
+                    bytes[chunk * B::ELEMENT_BYTES..(chunk + 1) * B::ELEMENT_BYTES].fill(0xff);
+                }
+                assert_eq!(
+                    CubeExtension::<B>::try_from(bytes.as_slice()).ok(),
+                    CubeExtension::<B>::from_random_bytes(&bytes)
+                );
+            }
+        }
+        check::<BaseElement>();
+        check::<f62::BaseElement>();
+        check::<f128::BaseElement>();
+
+        // f61 reduces random bytes, and thus, accepts almost all of them
+        let value_size = <CubeExtension<f61::BaseElement> as Randomizable>::VALUE_SIZE;
+        let bytes = vec![0xfe; value_size];
+        assert!(CubeExtension::<f61::BaseElement>::try_from(bytes.as_slice()).is_err());
+        assert!(CubeExtension::<f61::BaseElement>::from_random_bytes(&bytes).is_some());
+    }
+
+    #[test]
+    fn from_wide_bytes() {
+        let bytes: Vec<u8> = rand_vector::<u64>(8)
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+
+        // each coordinate is reduced from its own 21-byte chunk; the last byte is ignored
+        let expected = CubeExtension::new(
+            BaseElement::reduce_le_bytes(&bytes[..21]),
+            BaseElement::reduce_le_bytes(&bytes[21..42]),
+            BaseElement::reduce_le_bytes(&bytes[42..63]),
+        );
+        assert_eq!(expected, CubeExtension::from_wide_bytes(&bytes));
+        assert_eq!(expected, CubeExtension::from_wide_bytes(&bytes[..63]));
+
+        // inputs which would be rejected by from_random_bytes() are still mapped into the field
+        let element = CubeExtension::<BaseElement>::from_wide_bytes(&[0xff; 64]);
+        let max = BaseElement::reduce_le_bytes(&[0xff; 21]);
+        assert_eq!(CubeExtension::new(max, max, max), element);
+
+        // too short inputs produce ZERO coordinates
+        assert_eq!(
+            CubeExtension::<BaseElement>::ZERO,
+            CubeExtension::from_wide_bytes(&[1, 2])
+        );
+    }
+
+    #[test]
+    fn from_wide_bytes_uniformity() {
+        // chi-square test over the toy field with modulus 7: every coordinate is binned by its
+        // value; with 6 degrees of freedom, the probability of the statistic exceeding 40 for a
+        // uniform distribution is below 10^-6
+        const NUM_SAMPLES: usize = 7000;
+        let mut counts = [[0usize; 7]; 3];
+        for _ in 0..NUM_SAMPLES {
+            let bytes: Vec<u8> = rand_vector::<u64>(8)
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect();
+            let element = CubeExtension::<f7::BaseElement>::from_wide_bytes(&bytes);
+            for (i, value) in element.to_array().iter().enumerate() {
+                counts[i][value.as_int() as usize] += 1;
+            }
+        }
+
+        let expected = (NUM_SAMPLES / 7) as f64;
+        for coordinate_counts in counts.iter() {
+            let chi_square: f64 = coordinate_counts
+                .iter()
+                .map(|&count| (count as f64 - expected).powi(2) / expected)
+                .sum();
+            assert!(
+                chi_square < 40.0,
+                "chi-square statistic too large: {}",
+                chi_square
+            );
+        }
+    }
+
+    #[test]
+    fn rand_nonzero() {
+        for _ in 0..1000 {
+            let x = CubeExtension::<BaseElement>::rand_nonzero();
+            assert_ne!(CubeExtension::<BaseElement>::ZERO, x);
+        }
+
+        // the toy field draws ZERO once in every 343 draws on average
+        for _ in 0..1000 {
+            let x = CubeExtension::<f7::BaseElement>::rand_nonzero();
+            assert_ne!(CubeExtension::<f7::BaseElement>::ZERO, x);
+        }
+    }
+
+    #[test]
+    fn rand_nonzero_with_bytes() {
+        let fill_bytes = |bytes: &mut [u8]| {
+            for chunk in bytes.chunks_mut(8) {
+                chunk.copy_from_slice(&rand_value::<u64>().to_le_bytes()[..chunk.len()]);
+            }
+        };
+        for _ in 0..1000 {
+            let x = CubeExtension::<BaseElement>::rand_nonzero_with_bytes(fill_bytes).unwrap();
+            assert_ne!(CubeExtension::<BaseElement>::ZERO, x);
+        }
+
+        // ZERO draws are retried
+        let mut calls = 0;
+        let x = CubeExtension::<BaseElement>::rand_nonzero_with_bytes(|bytes: &mut [u8]| {
+            bytes.fill(0);
+            if calls == 3 {
+                bytes[0] = 1;
+            }
+            calls += 1;
+        });
+        assert_eq!(Some(CubeExtension::<BaseElement>::ONE), x);
+        assert_eq!(4, calls);
+
+        // a broken source of randomness
+        let x =
+            CubeExtension::<BaseElement>::rand_nonzero_with_bytes(|bytes: &mut [u8]| bytes.fill(0));
+        assert_eq!(None, x);
+    }
+
+    #[test]
+    fn lagrange_basis_at() {
+        let domain: Vec<BaseElement> = rand_vector(8);
+        let z: CubeExtension<BaseElement> = rand_value();
+        let result = CubeExtension::lagrange_basis_at(&domain, z);
+        assert_eq!(domain.len(), result.len());
+
+        // compare against the product form of the basis polynomials
+        for (i, &x_i) in domain.iter().enumerate() {
+            let mut expected = CubeExtension::ONE;
+            for (j, &x_j) in domain.iter().enumerate() {
+                if i != j {
+                    expected *= (z - CubeExtension::from(x_j)) / CubeExtension::from(x_i - x_j);
+                }
+            }
+            assert_eq!(expected, result[i]);
+        }
+
+        // the basis polynomials sum up to ONE at any point
+        let sum = result.iter().fold(CubeExtension::ZERO, |acc, &x| acc + x);
+        assert_eq!(CubeExtension::ONE, sum);
+
+        // at a domain point, the result is the corresponding unit vector
+        let result = CubeExtension::lagrange_basis_at(&domain, CubeExtension::from(domain[3]));
+        for (i, &value) in result.iter().enumerate() {
+            let expected = if i == 3 {
+                CubeExtension::ONE
+            } else {
+                CubeExtension::ZERO
+            };
+            assert_eq!(expected, value);
+        }
+
+        // the values can be used to evaluate the interpolating polynomial
+        let evals: Vec<CubeExtension<BaseElement>> = rand_vector(8);
+        let poly = CubeExtension::interpolate_from_base_domain(&domain, &evals);
+        let result = CubeExtension::lagrange_basis_at(&domain, z);
+        let expected = CubeExtension::linear_combination_ext(&evals, &result);
+        assert_eq!(expected, polynom::eval(&poly, z));
+
+        // empty domain
+        assert!(CubeExtension::<BaseElement>::lagrange_basis_at(&[], z).is_empty());
+    }
+
+    #[test]
+    fn all_elements() {
+        let mut elements: Vec<CubeExtension<f7::BaseElement>> =
+            CubeExtension::all_elements().collect();
+        assert_eq!(343, elements.len());
+        assert_eq!(CubeExtension::ZERO, elements[0]);
+        assert_eq!(CubeExtension::ONE, elements[1]);
+
+        // every element is yielded exactly once
+        elements.sort();
+        elements.dedup();
+        assert_eq!(343, elements.len());
+    }
+
+    #[test]
+    fn all_elements_inv() {
+        // exhaustively verify inversion over the toy field
+        for x in CubeExtension::<f7::BaseElement>::all_elements() {
+            if x == CubeExtension::ZERO {
+                assert_eq!(CubeExtension::ZERO, x.inv());
+            } else {
+                assert_eq!(CubeExtension::ONE, x * x.inv());
+                assert_eq!(x, x.inv().inv());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "all elements can be enumerated only for fields with small order")]
+    fn all_elements_large_field() {
+        let _ = CubeExtension::<BaseElement>::all_elements();
+    }
+
+    #[test]
+    fn multiplicative_generator() {
+        // the order of the generator in the toy field is checked exhaustively
+        let g = CubeExtension::<f7::BaseElement>::multiplicative_generator();
+        let order = g
+            .powers()
+            .skip(1)
+            .position(|x| x == CubeExtension::ONE)
+            .unwrap()
+            + 1;
+        assert_eq!(342, order);
+
+        // prime factors of p^3 - 1 for each of the base fields
+        check_generator_order::<f7::BaseElement>(&["2", "3", "19"]);
+        check_generator_order::<BaseElement>(&[
+            "2",
+            "3",
+            "5",
+            "17",
+            "257",
+            "937",
+            "65537",
+            "724723",
+            "167034643597991036904547663171",
+        ]);
+        check_generator_order::<f62::BaseElement>(&[
+            "2",
+            "13",
+            "17",
+            "37957",
+            "1018057",
+            "20889876597691432210877666899051",
+        ]);
+        check_generator_order::<f61::BaseElement>(&[
+            "2",
+            "3",
+            "5",
+            "7",
+            "11",
+            "13",
+            "31",
+            "41",
+            "61",
+            "151",
+            "331",
+            "1321",
+            "1772303994379887829769795077302561451",
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplicative generator is not defined for this extension field")]
+    fn multiplicative_generator_undefined() {
+        let _ = CubeExtension::<f128::BaseElement>::multiplicative_generator();
+    }
+
+    // TYPE CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn extension_of() {
+        check_extension_of::<BaseElement>(1);
+        check_extension_of::<QuadExtension<BaseElement>>(2);
+        check_extension_of::<CubeExtension<BaseElement>>(3);
+    }
+
+    #[test]
+    fn array_conversions() {
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(r, CubeExtension::from_array(r.to_array()));
+
+        let coeffs = [
+            BaseElement::new(1),
+            BaseElement::new(2),
+            BaseElement::new(3),
+        ];
+        let r = CubeExtension::<BaseElement>::from(coeffs);
+        assert_eq!(CubeExtension::new(coeffs[0], coeffs[1], coeffs[2]), r);
+        assert_eq!(coeffs, <[BaseElement; 3]>::from(r));
+    }
+
+    #[test]
+    fn montgomery_bytes() {
+        type E = CubeExtension<f62::BaseElement>;
+
+        for _ in 0..100 {
+            let x: E = rand_value();
+            let bytes = x.to_montgomery_bytes();
+            assert_eq!(E::ELEMENT_BYTES, bytes.len());
+            assert_eq!(x, E::from_montgomery_bytes(&bytes).unwrap());
+        }
+
+        // Montgomery encoding of (1, 2, -1), i.e., x * 2^64 mod p for each coordinate written as
+        // a little-endian u64; this is the layout used by libraries which keep elements of this
+        // field in Montgomery form with R = 2^64
+        let expected: [u8; 24] = [
+            0xfc, 0xff, 0xff, 0xff, 0xff, 0xdd, 0x00, 0x00, 0xf8, 0xff, 0xff, 0xff, 0xff, 0xbb,
+            0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x80, 0xea, 0xfe, 0x3f,
+        ];
+        let x = E::from_ints(1, 2, f62::BaseElement::MODULUS - 1);
+        assert_eq!(expected.to_vec(), x.to_montgomery_bytes());
+        assert_eq!(x, E::from_montgomery_bytes(&expected).unwrap());
+        assert_ne!(x.to_bytes(), x.to_montgomery_bytes());
+
+        // the encoding does not depend on the internal representation of the coordinates
+        let y: E = rand_value();
+        assert_eq!((y + x - y).to_montgomery_bytes(), x.to_montgomery_bytes());
+
+        // invalid encodings
+        let err = E::from_montgomery_bytes(&expected[..23]).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+        let mut bytes = expected;
+        bytes[16..].copy_from_slice(&f62::BaseElement::MODULUS.to_le_bytes());
+        let err = E::from_montgomery_bytes(&bytes).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+    }
+
+    #[test]
+    fn byte_array_conversions() {
+        let r: CubeExtension<BaseElement> = rand_value();
+        let bytes = <[u8; 24]>::from(r);
+        assert_eq!(r.to_bytes(), bytes);
+        assert_eq!(r, CubeExtension::try_from(&bytes[..]).unwrap());
+
+        // non-canonical internal values are written in canonical form
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        let bytes: [u8; 24] = CubeExtension(zero, BaseElement::ONE, zero).into();
+        assert_eq!(
+            CubeExtension::new(BaseElement::ZERO, BaseElement::ONE, BaseElement::ZERO),
+            CubeExtension::try_from(&bytes[..]).unwrap()
+        );
+        assert_eq!(1, bytes[8]);
+
+        // other base fields and towers
+        let r: CubeExtension<f61::BaseElement> = rand_value();
+        let bytes: [u8; 24] = r.into();
+        assert_eq!(r, CubeExtension::try_from(&bytes[..]).unwrap());
+
+        let t = rand_tower_value();
+        let bytes: [u8; 48] = t.into();
+        assert_eq!(t.to_bytes(), bytes);
+        assert_eq!(t, Tower::try_from(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn map_coords() {
+        let r: CubeExtension<BaseElement> = rand_value();
+        let expected = CubeExtension::new(
+            r.a() + BaseElement::ONE,
+            r.b() + BaseElement::ONE,
+            r.c() + BaseElement::ONE,
+        );
+        assert_eq!(expected, r.map_coords(|x| x + BaseElement::ONE));
+
+        // mapping with identity leaves the element unchanged
+        assert_eq!(r, r.map_coords(|x| x));
+    }
+
+    #[test]
+    fn from_ints() {
+        let expected = CubeExtension::new(
+            BaseElement::from(1u64),
+            BaseElement::from(2u64),
+            BaseElement::from(3u64),
+        );
+        assert_eq!(expected, CubeExtension::from_ints(1, 2, 3));
+
+        // values are reduced by the modulus of the base field
+        let m = BaseElement::MODULUS;
+        assert_eq!(expected, CubeExtension::from_ints(m + 1, 2, 3));
+
+        let result = CubeExtension::<BaseElement>::from_int_triples(&[(1, 2, 3), (0, 0, 0)]);
+        assert_eq!(vec![expected, CubeExtension::ZERO], result);
+        assert!(CubeExtension::<BaseElement>::from_int_triples(&[]).is_empty());
+
+        // towers lift the integers into the quadratic extension
+        let t = Tower::from_ints(1, 2, 3);
+        assert_eq!(QuadExtension::from(BaseElement::new(3)), t.c());
+    }
+
+    #[test]
+    fn coordinate_accessors() {
+        let a: BaseElement = rand_value();
+        let b: BaseElement = rand_value();
+        let c: BaseElement = rand_value();
+        let r = CubeExtension::new(a, b, c);
+        assert_eq!(a, r.a());
+        assert_eq!(b, r.b());
+        assert_eq!(c, r.c());
+        assert_eq!([r.a(), r.b(), r.c()], r.to_array());
+    }
+
+    #[test]
+    fn from_base_padded() {
+        let source: Vec<BaseElement> = rand_vector(5);
+        let zero = BaseElement::ZERO;
+
+        let result = CubeExtension::from_base_padded(&source[..3]);
+        assert_eq!(vec![CubeExtension(source[0], source[1], source[2])], result);
+
+        let result = CubeExtension::from_base_padded(&source[..4]);
+        let expected = vec![
+            CubeExtension(source[0], source[1], source[2]),
+            CubeExtension(source[3], zero, zero),
+        ];
+        assert_eq!(expected, result);
+
+        let result = CubeExtension::from_base_padded(&source);
+        let expected = vec![
+            CubeExtension(source[0], source[1], source[2]),
+            CubeExtension(source[3], source[4], zero),
+        ];
+        assert_eq!(expected, result);
+
+        assert!(CubeExtension::<BaseElement>::from_base_padded(&[]).is_empty());
+    }
+
+    #[test]
+    fn try_from_base_slice() {
+        let source: Vec<BaseElement> = rand_vector(4);
+
+        let result = CubeExtension::<BaseElement>::try_from(&source[..3]);
+        assert_eq!(
+            CubeExtension(source[0], source[1], source[2]),
+            result.unwrap()
+        );
+
+        for len in [2, 4] {
+            let result = CubeExtension::<BaseElement>::try_from(&source[..len]);
+            assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+        }
+    }
+
+    #[test]
+    fn from_base_vector() {
+        let source: Vec<BaseElement> = rand_vector(6);
+        let expected = vec![
+            CubeExtension(source[0], source[1], source[2]),
+            CubeExtension(source[3], source[4], source[5]),
+        ];
+
+        // capacity divisible by three: memory is re-interpreted
+        let mut v = Vec::with_capacity(6);
+        v.extend_from_slice(&source);
+        assert_eq!(expected, CubeExtension::from_base_vector(v).unwrap());
+
+        // capacity not divisible by three: elements are copied
+        let mut v = Vec::with_capacity(7);
+        v.extend_from_slice(&source);
+        let result = CubeExtension::from_base_vector(v).unwrap();
+        assert_eq!(expected, result);
+        assert_eq!(2, result.len());
+
+        // length not divisible by three
+        let v = source[..5].to_vec();
+        let err = CubeExtension::<BaseElement>::from_base_vector(v).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+    }
+
+    #[test]
+    fn from_u64_coords() {
+        type E = CubeExtension<BaseElement>;
+        const X: E = E::from_u64_coords(1, 2, 3);
+        const TABLE: [E; 3] = [E::from_u64_coords(0, 0, 0), E::from_u64_coords(1, 0, 0), X];
+        static REPEATED: [CubeExtension<f62::BaseElement>; 4] =
+            [CubeExtension::<f62::BaseElement>::from_u64_coords(4, 5, u64::MAX); 4];
+
+        assert_eq!(CubeExtension::from_ints(1, 2, 3), X);
+        assert_eq!(CubeExtension::ZERO, TABLE[0]);
+        assert_eq!(CubeExtension::ONE, TABLE[1]);
+        assert_eq!(X, TABLE[2]);
+        for &value in REPEATED.iter() {
+            assert_eq!(CubeExtension::from_ints(4, 5, u64::MAX), value);
+        }
+
+        // values are reduced modulo the base field modulus
+        let m = BaseElement::MODULUS;
+        let y = CubeExtension::<BaseElement>::from_u64_coords(m, m + 1, u64::MAX);
+        assert_eq!(CubeExtension::from_ints(0, 1, u64::MAX - m), y);
+    }
+
+    #[test]
+    fn from_base_iter() {
+        let source: Vec<BaseElement> = rand_vector(7);
+        let expected = vec![
+            CubeExtension(source[0], source[1], source[2]),
+            CubeExtension(source[3], source[4], source[5]),
+        ];
+
+        let result = CubeExtension::from_base_iter(source[..6].iter().copied()).unwrap();
+        assert_eq!(expected, result);
+
+        // empty iterator
+        let result = CubeExtension::<BaseElement>::from_base_iter(core::iter::empty()).unwrap();
+        assert!(result.is_empty());
+
+        // number of elements not divisible by three
+        for n in [1, 2, 4, 5, 7] {
+            let err = CubeExtension::from_base_iter(source.iter().copied().take(n)).unwrap_err();
+            assert_eq!(
+                DeserializationError::InvalidValue(format!(
+                    "number of base elements must be divisible by three, but was {}",
+                    n
+                )),
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn into_base_vec() {
+        let elements: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let expected: Vec<BaseElement> = elements.iter().flat_map(|e| e.to_array()).collect();
+        assert_eq!(expected, CubeExtension::to_base_vec(&elements));
+
+        // memory is re-interpreted, and the capacity is accounted for correctly
+        let mut source = Vec::with_capacity(7);
+        source.extend_from_slice(&elements);
+        let ptr = source.as_ptr() as usize;
+        let result = CubeExtension::into_base_vec(source);
+        assert_eq!(expected, result);
+        assert_eq!(ptr, result.as_ptr() as usize);
+        assert_eq!(12, result.len());
+        assert_eq!(21, result.capacity());
+
+        // the vector can be grown and converted back without copying
+        let mut result = result;
+        result.extend_from_slice(&[BaseElement::ONE; 3]);
+        let ptr = result.as_ptr() as usize;
+        let back = CubeExtension::from_base_vector(result).unwrap();
+        assert_eq!(ptr, back.as_ptr() as usize);
+        assert_eq!(&elements[..], &back[..4]);
+        assert_eq!(
+            CubeExtension::new(BaseElement::ONE, BaseElement::ONE, BaseElement::ONE),
+            back[4]
+        );
+        assert_eq!(7, back.capacity());
+
+        // empty vectors
+        let empty = CubeExtension::<BaseElement>::into_base_vec(Vec::new());
+        assert!(empty.is_empty());
+        assert!(CubeExtension::<BaseElement>::to_base_vec(&[]).is_empty());
+    }
+
+    #[test]
+    fn into_array_vec() {
+        let elements: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let expected: Vec<[BaseElement; 3]> = elements.iter().map(|e| e.to_array()).collect();
+
+        // memory is re-interpreted, and the capacity is preserved
+        let mut source = Vec::with_capacity(7);
+        source.extend_from_slice(&elements);
+        let ptr = source.as_ptr() as usize;
+        let result = CubeExtension::into_array_vec(source);
+        assert_eq!(expected, result);
+        assert_eq!(ptr, result.as_ptr() as usize);
+        assert_eq!(4, result.len());
+        assert_eq!(7, result.capacity());
+
+        // the vector can be grown and converted back without copying
+        let mut result = result;
+        result.push([BaseElement::ONE; 3]);
+        let ptr = result.as_ptr() as usize;
+        let back = CubeExtension::from_array_vec(result);
+        assert_eq!(ptr, back.as_ptr() as usize);
+        assert_eq!(&elements[..], &back[..4]);
+        assert_eq!(CubeExtension::from_array([BaseElement::ONE; 3]), back[4]);
+        assert_eq!(7, back.capacity());
+
+        // empty vectors
+        assert!(CubeExtension::<BaseElement>::into_array_vec(Vec::new()).is_empty());
+        assert!(CubeExtension::<BaseElement>::from_array_vec(Vec::new()).is_empty());
     }
-}
 
-// TESTS
-// ================================================================================================
+    #[test]
+    fn deinterleave() {
+        let elements: Vec<CubeExtension<BaseElement>> = rand_vector(5);
+        let (a, b, c) = CubeExtension::deinterleave(&elements);
+        assert_eq!(elements.iter().map(|e| e.0).collect::<Vec<_>>(), a);
+        assert_eq!(elements.iter().map(|e| e.1).collect::<Vec<_>>(), b);
+        assert_eq!(elements.iter().map(|e| e.2).collect::<Vec<_>>(), c);
+        assert_eq!(elements, CubeExtension::interleave(&a, &b, &c));
 
-#[cfg(test)]
-mod tests {
-    use super::{CubeExtension, DeserializationError, FieldElement, Vec};
-    use crate::field::f64::BaseElement;
-    use rand_utils::rand_value;
+        // empty slices
+        let (a, b, c) = CubeExtension::<BaseElement>::deinterleave(&[]);
+        assert!(a.is_empty() && b.is_empty() && c.is_empty());
+        assert!(CubeExtension::<BaseElement>::interleave(&[], &[], &[]).is_empty());
+    }
 
-    // BASIC ALGEBRA
-    // --------------------------------------------------------------------------------------------
+    #[test]
+    #[should_panic(expected = "number of values must be the same for all slices")]
+    fn interleave_length_mismatch() {
+        let a: Vec<BaseElement> = rand_vector(4);
+        let b: Vec<BaseElement> = rand_vector(4);
+        let c: Vec<BaseElement> = rand_vector(3);
+        CubeExtension::interleave(&a, &b, &c);
+    }
 
     #[test]
-    fn add() {
-        // identity
-        let r: CubeExtension<BaseElement> = rand_value();
-        assert_eq!(r, r + CubeExtension::<BaseElement>::ZERO);
+    fn flatten_columns() {
+        // a table with 4 rows and 2 columns
+        let table: Vec<Vec<CubeExtension<BaseElement>>> = vec![rand_vector(4), rand_vector(4)];
+        let flat = CubeExtension::flatten_columns(&table);
+        assert_eq!(6, flat.len());
+        for (i, column) in table.iter().enumerate() {
+            for (j, element) in column.iter().enumerate() {
+                assert_eq!(element.0, flat[3 * i][j]);
+                assert_eq!(element.1, flat[3 * i + 1][j]);
+                assert_eq!(element.2, flat[3 * i + 2][j]);
+            }
+        }
+        assert_eq!(table, CubeExtension::unflatten_columns(&flat));
 
-        // test random values
-        let r1: CubeExtension<BaseElement> = rand_value();
-        let r2: CubeExtension<BaseElement> = rand_value();
+        // empty tables
+        assert!(CubeExtension::<BaseElement>::flatten_columns(&[]).is_empty());
+        assert!(CubeExtension::<BaseElement>::unflatten_columns(&[]).is_empty());
+    }
 
-        let expected = CubeExtension(r1.0 + r2.0, r1.1 + r2.1, r1.2 + r2.2);
-        assert_eq!(expected, r1 + r2);
+    #[test]
+    #[should_panic(expected = "number of base columns must be divisible by three, but was 2")]
+    fn unflatten_columns_wrong_number_of_columns() {
+        let table: Vec<Vec<BaseElement>> = vec![rand_vector(4), rand_vector(4)];
+        CubeExtension::unflatten_columns(&table);
     }
 
     #[test]
-    fn sub() {
-        // identity
-        let r: CubeExtension<BaseElement> = rand_value();
-        assert_eq!(r, r - CubeExtension::<BaseElement>::ZERO);
+    #[should_panic(
+        expected = "base columns fused into an extension column must have the same length"
+    )]
+    fn unflatten_columns_wrong_column_length() {
+        let table: Vec<Vec<BaseElement>> = vec![rand_vector(4), rand_vector(3), rand_vector(4)];
+        CubeExtension::unflatten_columns(&table);
+    }
 
-        // test random values
-        let r1: CubeExtension<BaseElement> = rand_value();
-        let r2: CubeExtension<BaseElement> = rand_value();
+    #[test]
+    fn from_base_slice() {
+        let source: Vec<BaseElement> = rand_vector(6);
+        let expected = vec![
+            CubeExtension(source[0], source[1], source[2]),
+            CubeExtension(source[3], source[4], source[5]),
+        ];
 
-        let expected = CubeExtension(r1.0 - r2.0, r1.1 - r2.1, r1.2 - r2.2);
-        assert_eq!(expected, r1 - r2);
+        let result = CubeExtension::from_base_slice(&source).unwrap();
+        assert_eq!(expected, result);
+        assert_eq!(source, CubeExtension::as_base_elements(result));
+
+        // an empty slice is converted into an empty slice
+        let result = CubeExtension::<BaseElement>::from_base_slice(&[]).unwrap();
+        assert!(result.is_empty());
+
+        // length not divisible by three
+        let err = CubeExtension::from_base_slice(&source[..4]).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
     }
 
-    // INITIALIZATION
-    // --------------------------------------------------------------------------------------------
+    // the layout of extension elements over all supported base fields is checked at compile time
+    const _: () =
+        assert!(mem::size_of::<CubeExtension<BaseElement>>() == 3 * BaseElement::ELEMENT_BYTES);
+    const _: () = assert!(
+        mem::size_of::<CubeExtension<f62::BaseElement>>() == 3 * f62::BaseElement::ELEMENT_BYTES
+    );
+    const _: () = assert!(
+        mem::size_of::<CubeExtension<f61::BaseElement>>() == 3 * f61::BaseElement::ELEMENT_BYTES
+    );
+    const _: () = assert!(
+        mem::size_of::<CubeExtension<f7::BaseElement>>() == 3 * f7::BaseElement::ELEMENT_BYTES
+    );
+    const _: () = assert!(mem::size_of::<Tower>() == 6 * BaseElement::ELEMENT_BYTES);
+
+    // extension elements can be re-interpreted as arrays of their coefficients
+    const _: () = assert!(
+        mem::size_of::<CubeExtension<BaseElement>>() == mem::size_of::<[BaseElement; 3]>()
+            && mem::align_of::<CubeExtension<BaseElement>>() == mem::align_of::<[BaseElement; 3]>()
+    );
 
     #[test]
-    fn zeroed_vector() {
-        let result = CubeExtension::<BaseElement>::zeroed_vector(4);
-        assert_eq!(4, result.len());
-        for element in result.into_iter() {
-            assert_eq!(CubeExtension::<BaseElement>::ZERO, element);
-        }
+    fn memory_layout() {
+        check_memory_layout::<BaseElement>();
+        check_memory_layout::<f62::BaseElement>();
+        check_memory_layout::<f61::BaseElement>();
+        check_memory_layout::<f7::BaseElement>();
+        check_memory_layout::<QuadExtension<BaseElement>>();
     }
 
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
     #[test]
+    #[cfg_attr(
+        target_endian = "big",
+        should_panic(expected = "byte views of cubic extension elements")
+    )]
     fn elements_as_bytes() {
         let source = vec![
             CubeExtension(
@@ -403,6 +4765,159 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_batch_into() {
+        // f64 field is non-canonical; ZERO computed as -1 + 1 has a non-canonical internal value
+        let mut elements: Vec<CubeExtension<BaseElement>> = rand_vector(16);
+        elements[3] = CubeExtension::new(
+            -BaseElement::ONE + BaseElement::ONE,
+            BaseElement::ONE,
+            BaseElement::ZERO,
+        );
+        check_write_batch_into(&elements);
+        check_write_batch_into(&[rand_tower_value(), rand_tower_value()]);
+
+        // f61 and f7 fields are canonical, and thus, bytes are written in a single call
+        check_write_batch_into(&rand_vector::<CubeExtension<f61::BaseElement>>(16));
+        check_write_batch_into(
+            &CubeExtension::<f7::BaseElement>::all_elements().collect::<Vec<_>>(),
+        );
+        check_write_batch_into::<CubeExtension<f61::BaseElement>>(&[]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        target_endian = "big",
+        should_panic(expected = "byte views of cubic extension elements")
+    )]
+    fn as_bytes() {
+        let e = CubeExtension(
+            BaseElement::new(1),
+            BaseElement::new(2),
+            BaseElement::new(3),
+        );
+
+        // the serialized form is little-endian on all targets
+        let expected: Vec<u8> = vec![
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(expected, e.to_bytes());
+
+        // the in-memory encoding must agree with the serialized form; on big-endian targets, the
+        // byte view is not available
+        assert_eq!(expected, e.as_bytes());
+    }
+
+    #[test]
+    fn compact_serialization() {
+        // element in the base field
+        let e = CubeExtension::<BaseElement>::from(rand_value::<BaseElement>());
+        let mut bytes = Vec::new();
+        e.write_into_compact(&mut bytes);
+        assert_eq!(1 + BaseElement::ELEMENT_BYTES, bytes.len());
+        assert_eq!(0, bytes[0]);
+        let mut reader = SliceReader::new(&bytes);
+        assert_eq!(e, CubeExtension::read_from_compact(&mut reader).unwrap());
+        assert!(!reader.has_more_bytes());
+
+        // element outside of the base field
+        let e: CubeExtension<BaseElement> = rand_value();
+        let mut bytes = Vec::new();
+        e.write_into_compact(&mut bytes);
+        assert_eq!(1 + CubeExtension::<BaseElement>::ELEMENT_BYTES, bytes.len());
+        assert_eq!(1, bytes[0]);
+        assert_eq!(e.to_bytes(), bytes[1..]);
+        let mut reader = SliceReader::new(&bytes);
+        assert_eq!(e, CubeExtension::read_from_compact(&mut reader).unwrap());
+
+        // invalid tag
+        bytes[0] = 2;
+        let mut reader = SliceReader::new(&bytes);
+        let err = CubeExtension::<BaseElement>::read_from_compact(&mut reader).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+    }
+
+    #[test]
+    fn vec_serialization() {
+        // empty vector is encoded as a single ZERO byte
+        let mut bytes = Vec::new();
+        CubeExtension::<BaseElement>::write_vec_into(&[], &mut bytes);
+        assert_eq!(vec![0], bytes);
+        let mut reader = SliceReader::new(&bytes);
+        let result = CubeExtension::<BaseElement>::read_vec_from(&mut reader).unwrap();
+        assert!(result.is_empty());
+
+        for n in [1, 5, 200] {
+            let elements: Vec<CubeExtension<BaseElement>> = rand_vector(n);
+            let mut bytes = Vec::new();
+            CubeExtension::write_vec_into(&elements, &mut bytes);
+
+            // lengths below 128 take a single byte; 200 takes two bytes
+            let prefix_len = if n < 128 { 1 } else { 2 };
+            let element_bytes = CubeExtension::<BaseElement>::ELEMENT_BYTES;
+            assert_eq!(prefix_len + n * element_bytes, bytes.len());
+
+            let mut reader = SliceReader::new(&bytes);
+            assert_eq!(elements, CubeExtension::read_vec_from(&mut reader).unwrap());
+            assert!(!reader.has_more_bytes());
+        }
+    }
+
+    #[test]
+    fn read_canonical_from() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        let bytes = x.to_bytes();
+        let mut reader = SliceReader::new(&bytes);
+        assert_eq!(x, CubeExtension::read_canonical_from(&mut reader).unwrap());
+        assert!(!reader.has_more_bytes());
+
+        // a coordinate equal to the modulus is rejected
+        let mut bytes = x.to_bytes();
+        bytes[8..16].copy_from_slice(&BaseElement::MODULUS.to_le_bytes());
+        let mut reader = SliceReader::new(&bytes);
+        let err = CubeExtension::<BaseElement>::read_canonical_from(&mut reader).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+
+        // a coordinate greater than the modulus is rejected
+        let mut bytes = x.to_bytes();
+        bytes[16..].copy_from_slice(&u64::MAX.to_le_bytes());
+        let mut reader = SliceReader::new(&bytes);
+        let err = CubeExtension::<BaseElement>::read_canonical_from(&mut reader).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+
+        // not enough bytes
+        let mut reader = SliceReader::new(&bytes[..23]);
+        let err = CubeExtension::<BaseElement>::read_canonical_from(&mut reader).unwrap_err();
+        assert_eq!(DeserializationError::UnexpectedEOF, err);
+    }
+
+    #[test]
+    fn vec_serialization_truncated() {
+        let elements: Vec<CubeExtension<BaseElement>> = rand_vector(4);
+        let mut bytes = Vec::new();
+        CubeExtension::write_vec_into(&elements, &mut bytes);
+
+        // missing part of the last element
+        let mut reader = SliceReader::new(&bytes[..bytes.len() - 1]);
+        let err = CubeExtension::<BaseElement>::read_vec_from(&mut reader).unwrap_err();
+        assert_eq!(DeserializationError::UnexpectedEOF, err);
+
+        // length prefix claims more elements than there are bytes for
+        let mut reader = SliceReader::new(&[0xff, 0xff, 0xff, 0xff, 0x0f]);
+        let err = CubeExtension::<BaseElement>::read_vec_from(&mut reader).unwrap_err();
+        assert_eq!(DeserializationError::UnexpectedEOF, err);
+
+        // length prefix is not terminated
+        let mut reader = SliceReader::new(&[0x80]);
+        let err = CubeExtension::<BaseElement>::read_vec_from(&mut reader).unwrap_err();
+        assert_eq!(DeserializationError::UnexpectedEOF, err);
+
+        // length prefix overflows 64 bits
+        let mut reader = SliceReader::new(&[0xff; 11]);
+        let err = CubeExtension::<BaseElement>::read_vec_from(&mut reader).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)));
+    }
+
     #[test]
     fn bytes_as_elements() {
         let bytes: Vec<u8> = vec![
@@ -431,12 +4946,197 @@ mod tests {
         assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
 
         let result = unsafe { CubeExtension::<BaseElement>::bytes_as_elements(&bytes[1..]) };
-        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+        match result {
+            Err(DeserializationError::InvalidValue(msg)) => {
+                assert!(msg.contains("expected alignment of 8 bytes"), "{}", msg);
+                assert!(msg.contains("was 1 bytes"), "{}", msg);
+            }
+            _ => panic!("expected alignment error"),
+        }
+    }
+
+    #[test]
+    fn bytes_as_elements_empty() {
+        let result = unsafe { CubeExtension::<BaseElement>::bytes_as_elements(&[]) };
+        assert_eq!(Ok(&[][..]), result);
+
+        // an empty slice which starts at a misaligned address is accepted as well
+        let bytes = [0u8; 16];
+        for offset in 0..bytes.len() {
+            let result =
+                unsafe { CubeExtension::<BaseElement>::bytes_as_elements(&bytes[offset..offset]) };
+            assert!(result.unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn checked_byte_count() {
+        assert_eq!(24, CubeExtension::<BaseElement>::ELEMENT_BYTES);
+        assert_eq!(48, super::checked_byte_count(2, 24).unwrap());
+        assert_eq!(0, super::checked_byte_count(0, usize::MAX).unwrap());
+
+        // oversized inputs are rejected rather than wrapped
+        let element_bytes = CubeExtension::<BaseElement>::ELEMENT_BYTES;
+        let result = super::checked_byte_count(usize::MAX / element_bytes + 1, element_bytes);
+        match result {
+            Err(DeserializationError::InvalidValue(msg)) => {
+                assert!(msg.contains("does not fit into usize"), "{}", msg);
+            }
+            _ => panic!("expected overflow error"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode() {
+        let x: CubeExtension<BaseElement> = rand_value();
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(24, bytes.len());
+        assert_eq!(x, bincode::deserialize(&bytes).unwrap());
+
+        // non-canonical base field values are rejected
+        let bytes = bincode::serialize(&(u64::MAX, 0u64, 0u64)).unwrap();
+        assert!(bincode::deserialize::<CubeExtension<BaseElement>>(&bytes).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_json() {
+        let x = CubeExtension(
+            BaseElement::new(1),
+            BaseElement::new(2),
+            BaseElement::new(BaseElement::MODULUS - 1),
+        );
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!("[1,2,18446744069414584320]", json);
+        assert_eq!(x, serde_json::from_str(&json).unwrap());
+
+        let x: CubeExtension<BaseElement> = rand_value();
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(x, serde_json::from_str(&json).unwrap());
+
+        assert!(serde_json::from_str::<CubeExtension<BaseElement>>("[1,2]").is_err());
+        assert!(
+            serde_json::from_str::<CubeExtension<BaseElement>>("[1,2,18446744069414584321]")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn deserialization_seed_corpus() {
+        // boundary lengths around one tower element (48 bytes) and two f64 cubic elements
+        for &len in &[0, 1, 23, 24, 25, 47, 48, 49, 72, 96] {
+            for &fill in &[0x00, 0x01, 0xff] {
+                for offset in 0..BaseElement::ELEMENT_BYTES {
+                    let bytes = vec![fill; len];
+                    check_deserialization::<CubeExtension<BaseElement>>(&bytes, offset);
+                    check_deserialization::<Tower>(&bytes, offset);
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn deserialization_proptest(
+            bytes in prop::collection::vec(any::<u8>(), 0..200),
+            offset in 0..BaseElement::ELEMENT_BYTES,
+        ) {
+            check_deserialization::<CubeExtension<BaseElement>>(&bytes, offset);
+            check_deserialization::<Tower>(&bytes, offset);
+        }
     }
 
     // UTILITIES
     // --------------------------------------------------------------------------------------------
 
+    #[test]
+    fn to_canonical_string() {
+        // f62 field stores elements in Montgomery form internally
+        use crate::field::f62::BaseElement as MontElement;
+
+        let a = CubeExtension(
+            MontElement::new(1),
+            MontElement::new(2),
+            MontElement::new(3),
+        );
+        assert_eq!("(1, 2, 3)", a.to_canonical_string());
+        assert_eq!(a.to_canonical_string(), a.to_string());
+
+        // non-canonical internal representation of ZERO is printed canonically
+        let b = CubeExtension(
+            -BaseElement::ONE + BaseElement::ONE,
+            BaseElement::ONE,
+            -BaseElement::ONE,
+        );
+        let expected = format!("(0, 1, {})", BaseElement::MODULUS - 1);
+        assert_eq!(expected, b.to_canonical_string());
+    }
+
+    #[test]
+    fn ordering() {
+        let mut elements: Vec<CubeExtension<BaseElement>> = rand_vector(64);
+        // include elements which differ only in later coordinates
+        let a = elements[0];
+        elements.push(CubeExtension(a.0, a.1, a.2 + BaseElement::ONE));
+        elements.push(CubeExtension(a.0, a.1 + BaseElement::ONE, a.2));
+        elements.reverse();
+
+        let mut sorted = elements.clone();
+        sorted.sort();
+
+        // sorting must be consistent with lexicographic ordering of canonical coordinates
+        for pair in sorted.windows(2) {
+            let a = pair[0].to_array().map(|c| c.as_int());
+            let b = pair[1].to_array().map(|c| c.as_int());
+            assert!(a <= b);
+        }
+
+        // total order axioms
+        for a in elements.iter() {
+            assert_eq!(Ordering::Equal, a.cmp(a));
+            for b in elements.iter() {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+                for c in elements.iter().step_by(8) {
+                    if a <= b && b <= c {
+                        assert!(a <= c);
+                    }
+                }
+            }
+        }
+
+        // sorting is independent of the internal representation
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        let a = CubeExtension(zero, BaseElement::ONE, zero);
+        let b = CubeExtension(BaseElement::ZERO, BaseElement::ONE, BaseElement::ZERO);
+        assert_eq!(Ordering::Equal, a.cmp(&b));
+        assert!(CubeExtension::<BaseElement>::ONE > a);
+        let mut v1 = vec![CubeExtension::ONE, a];
+        let mut v2 = vec![CubeExtension::ONE, b];
+        v1.sort();
+        v2.sort();
+        assert_eq!(v1, v2);
+        assert_eq!(a, v1[0]);
+    }
+
+    #[test]
+    fn cmp_by_a() {
+        let a: CubeExtension<BaseElement> = rand_value();
+        let b = CubeExtension(a.0, a.1 + BaseElement::ONE, a.2);
+        assert_ne!(a, b);
+        assert_ne!(Ordering::Equal, a.cmp(&b));
+        assert_eq!(Ordering::Equal, a.cmp_by_a(&b));
+
+        // canonical values of α are compared, regardless of the internal representation
+        let zero = -BaseElement::ONE + BaseElement::ONE;
+        let c = CubeExtension(zero, BaseElement::ONE, zero);
+        let d = CubeExtension(BaseElement::ZERO, BaseElement::ZERO, BaseElement::ONE);
+        assert_eq!(Ordering::Equal, c.cmp_by_a(&d));
+        assert_eq!(Ordering::Less, c.cmp_by_a(&CubeExtension::ONE));
+        assert_eq!(Ordering::Greater, CubeExtension::ONE.cmp_by_a(&d));
+    }
+
     #[test]
     fn as_base_elements() {
         let elements = vec![
@@ -466,4 +5166,410 @@ mod tests {
             CubeExtension::<BaseElement>::as_base_elements(&elements)
         );
     }
+
+    #[test]
+    fn as_base_elements_mut() {
+        let source: Vec<CubeExtension<BaseElement>> = rand_vector(8);
+
+        // scaling the base view scales the extension elements
+        let mut elements = source.clone();
+        let k: BaseElement = rand_value();
+        for value in CubeExtension::<BaseElement>::as_base_elements_mut(&mut elements) {
+            *value *= k;
+        }
+        let expected = source.iter().map(|e| e.mul_base(k)).collect::<Vec<_>>();
+        assert_eq!(expected, elements);
+
+        // zeroing the last two coordinates of each element leaves only the first coordinate
+        let mut elements = source.clone();
+        let base = CubeExtension::<BaseElement>::as_base_elements_mut(&mut elements);
+        assert_eq!(source.len() * 3, base.len());
+        for chunk in base.chunks_mut(3) {
+            chunk[1] = BaseElement::ZERO;
+            chunk[2] = BaseElement::ZERO;
+        }
+        let expected = source
+            .iter()
+            .map(|e| CubeExtension::<BaseElement>::from(e.a()))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, elements);
+
+        // towers expose the underlying StarkField elements
+        let mut elements = vec![rand_tower_value(), rand_tower_value()];
+        let expected = Tower::as_base_elements(&elements).to_vec();
+        let base = Tower::as_base_elements_mut(&mut elements);
+        assert_eq!(expected, base);
+        base[11] = BaseElement::ONE;
+        assert_eq!(
+            BaseElement::ONE,
+            QuadExtension::as_base_elements(&[elements[1].c()])[1]
+        );
+
+        // the toy field relies on the default implementation
+        let mut elements: Vec<CubeExtension<f7::BaseElement>> =
+            CubeExtension::all_elements().take(8).collect();
+        let expected = CubeExtension::as_base_elements(&elements).to_vec();
+        let base = CubeExtension::as_base_elements_mut(&mut elements);
+        assert_eq!(expected, base);
+        base[5] = f7::BaseElement::new(6);
+        assert_eq!(f7::BaseElement::new(6), elements[1].c());
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let a: BaseElement = rand_value();
+        let b: BaseElement = rand_value();
+        let c: BaseElement = rand_value();
+
+        // -1 + 1 leaves the base element in a non-canonical internal representation of ZERO
+        let x = CubeExtension(BaseElement::ZERO, b, c);
+        let y = CubeExtension(-BaseElement::ONE + BaseElement::ONE, b, c);
+        assert_ne!(x.as_bytes(), y.as_bytes());
+
+        let z = CubeExtension(a, b, c);
+        let w = (z * x) / z;
+
+        let mut set = HashSet::new();
+        set.insert(x);
+        set.insert(y);
+        set.insert(w);
+        assert_eq!(1, set.len());
+    }
+
+    // TOWER OF EXTENSIONS
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn tower_field_axioms() {
+        assert!(CubeExtension::<QuadExtension<BaseElement>>::is_supported());
+
+        let a = rand_tower_value();
+        let b = rand_tower_value();
+        let c = rand_tower_value();
+
+        assert_eq!(a * b, b * a);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * (b + c), a * b + a * c);
+        assert_eq!(a, a * Tower::ONE);
+        assert_eq!(a.square(), a * a);
+
+        // embedding of base and quadratic extension elements
+        let x: BaseElement = rand_value();
+        let y: BaseElement = rand_value();
+        assert_eq!(Tower::from(x * y), Tower::from(x) * Tower::from(y));
+        assert_eq!(
+            Tower::from(QuadExtension::from(x)),
+            Tower::new(
+                QuadExtension::from(x),
+                QuadExtension::ZERO,
+                QuadExtension::ZERO
+            )
+        );
+
+        // inversion
+        assert_eq!(Tower::ONE, a * a.inv());
+        assert_eq!(Tower::ZERO, Tower::ZERO.inv());
+        assert_eq!(a, (a * b) / b);
+    }
+
+    #[test]
+    fn tower_irreducible_mul() {
+        // the hand-written tower multiplication must match the default one derived from the
+        // lifted cubic polynomial
+        let coeffs =
+            <QuadExtension<BaseElement> as ExtensibleField<3>>::IRREDUCIBLE_COEFFS.unwrap();
+        for _ in 0..100 {
+            let a = rand_tower_value().to_array();
+            let b = rand_tower_value().to_array();
+            assert_eq!(
+                mul_with_irreducible(a, b, &coeffs),
+                <QuadExtension<BaseElement> as ExtensibleField<3>>::mul(a, b)
+            );
+        }
+    }
+
+    #[test]
+    fn tower_frobenius() {
+        // frobenius is relative to the quadratic extension: x -> x^(p^2)
+        let a = rand_tower_value();
+        let p = BaseElement::MODULUS;
+        assert_eq!(a.exp(p).exp(p), a.frobenius());
+        assert_eq!(a, a.frobenius().frobenius().frobenius());
+
+        // elements of the quadratic extension are fixed by frobenius
+        let q: QuadExtension<BaseElement> = rand_value();
+        assert_eq!(Tower::from(q), Tower::from(q).frobenius());
+
+        // norm lies in the quadratic extension and is multiplicative
+        let b = rand_tower_value();
+        assert_eq!(a.norm() * b.norm(), (a * b).norm());
+    }
+
+    #[test]
+    fn tower_serialization() {
+        let a = rand_tower_value();
+        assert_eq!(48, Tower::ELEMENT_BYTES);
+
+        let bytes = a.to_bytes();
+        assert_eq!(Tower::ELEMENT_BYTES, bytes.len());
+        assert_eq!(a, Tower::try_from(&bytes[..]).unwrap());
+        assert_eq!(bytes, a.as_bytes());
+
+        let elements = vec![a, rand_tower_value()];
+        let base = Tower::as_base_elements(&elements);
+        assert_eq!(12, base.len());
+        assert_eq!(QuadExtension::new(base[0], base[1]), a.0);
+        assert_eq!(QuadExtension::new(base[4], base[5]), a.2);
+
+        let mut reader = SliceReader::new(Tower::elements_as_bytes(&elements));
+        assert_eq!(elements, Tower::read_batch_from(&mut reader, 2).unwrap());
+    }
+
+    #[test]
+    fn tower_extension_degree() {
+        const _: () = assert!(Tower::EXTENSION_DEGREE == 6);
+        const _: () = assert!(Tower::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 6);
+    }
+
+    // FIELD AXIOMS
+    // --------------------------------------------------------------------------------------------
+
+    proptest! {
+        #[test]
+        fn add_axioms_proptest(x in cube_strategy(), y in cube_strategy(), z in cube_strategy()) {
+            prop_assert_eq!((x + y) + z, x + (y + z));
+            prop_assert_eq!(x + y, y + x);
+            prop_assert_eq!(x, x + CubeExtension::ZERO);
+            prop_assert_eq!(CubeExtension::ZERO, x + (-x));
+            prop_assert_eq!(x - y, x + (-y));
+        }
+
+        #[test]
+        fn mul_axioms_proptest(x in cube_strategy(), y in cube_strategy(), z in cube_strategy()) {
+            prop_assert_eq!((x * y) * z, x * (y * z));
+            prop_assert_eq!(x * y, y * x);
+            prop_assert_eq!(x, x * CubeExtension::ONE);
+            prop_assert_eq!(x * (y + z), x * y + x * z);
+            prop_assert_eq!((x + y) * z, x * z + y * z);
+        }
+
+        #[test]
+        fn inv_proptest(x in cube_strategy()) {
+            prop_assume!(x != CubeExtension::ZERO);
+            prop_assert_eq!(CubeExtension::ONE, x * x.inv());
+            prop_assert_eq!(x, x.inv().inv());
+        }
+
+        #[test]
+        fn frobenius_order_proptest(x in cube_strategy(), y in cube_strategy()) {
+            prop_assert_eq!(x, x.conjugate().conjugate().conjugate());
+            prop_assert_eq!((x * y).conjugate(), x.conjugate() * y.conjugate());
+        }
+
+        #[test]
+        fn tower_axioms_proptest(x in tower_strategy(), y in tower_strategy(), z in tower_strategy()) {
+            prop_assert_eq!((x * y) * z, x * (y * z));
+            prop_assert_eq!(x * (y + z), x * y + x * z);
+            prop_assert_eq!(Tower::ZERO, x + (-x));
+            prop_assert_eq!(x, x.conjugate().conjugate().conjugate());
+            if x != Tower::ZERO {
+                prop_assert_eq!(Tower::ONE, x * x.inv());
+            }
+        }
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Generates cubic extension elements from arbitrary 64-bit coordinates; these include values
+    /// with non-canonical internal representations and shrink towards small coordinates.
+    fn cube_strategy() -> impl Strategy<Value = CubeExtension<BaseElement>> {
+        (any::<u64>(), any::<u64>(), any::<u64>())
+            .prop_map(|(a, b, c)| CubeExtension::from_ints(a, b, c))
+    }
+
+    fn tower_strategy() -> impl Strategy<Value = Tower> {
+        prop::array::uniform6(any::<u64>()).prop_map(|v| {
+            Tower::new(
+                QuadExtension::new(BaseElement::new(v[0]), BaseElement::new(v[1])),
+                QuadExtension::new(BaseElement::new(v[2]), BaseElement::new(v[3])),
+                QuadExtension::new(BaseElement::new(v[4]), BaseElement::new(v[5])),
+            )
+        })
+    }
+
+    type Tower = CubeExtension<QuadExtension<BaseElement>>;
+
+    fn rand_tower_value() -> Tower {
+        // 48-byte tower elements are too large to be drawn by rand_value() directly
+        Tower::new(rand_value(), rand_value(), rand_value())
+    }
+
+    fn check_extension_of<E: ExtensionOf<BaseElement>>(degree: usize) {
+        assert_eq!(degree, E::base_degree());
+        assert_eq!(E::EXTENSION_DEGREE, E::base_degree());
+
+        // base elements are embedded as the first coordinate
+        let x: BaseElement = rand_value();
+        let y: BaseElement = rand_value();
+        let mut expected = vec![BaseElement::ZERO; degree];
+        expected[0] = x;
+        assert_eq!(expected, E::from_base(x).to_base_coords());
+
+        // the embedding preserves field operations
+        assert_eq!(E::from_base(x + y), E::from_base(x) + E::from_base(y));
+        assert_eq!(E::from_base(x * y), E::from_base(x) * E::from_base(y));
+        assert_eq!(E::ONE, E::from_base(BaseElement::ONE));
+
+        // coordinates are the same as the ones exposed via as_base_elements()
+        let r: E = rand_value();
+        let coords = r.to_base_coords();
+        assert_eq!(degree, coords.len());
+        assert_eq!(E::as_base_elements(&[r]), coords.as_slice());
+    }
+
+    /// Copies `bytes` into a buffer at the specified offset from a u64-aligned address, and feeds
+    /// the copy into all deserialization paths. Each of them must either return a valid result or
+    /// an error, but must never panic.
+    fn check_deserialization<E>(bytes: &[u8], offset: usize)
+    where
+        E: FieldElement + for<'a> TryFrom<&'a [u8], Error = DeserializationError>,
+    {
+        let mut buffer = vec![0u64; (bytes.len() + offset + 7) / 8];
+        let buffer = unsafe {
+            core::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8)
+        };
+        buffer[offset..offset + bytes.len()].copy_from_slice(bytes);
+        let bytes = &buffer[offset..offset + bytes.len()];
+
+        match unsafe { E::bytes_as_elements(bytes) } {
+            Ok(elements) => {
+                // empty slices are accepted regardless of their alignment
+                assert!(bytes.is_empty() || offset % BaseElement::ELEMENT_BYTES == 0);
+                assert_eq!(elements.len() * E::ELEMENT_BYTES, bytes.len());
+                assert_eq!(bytes, E::elements_as_bytes(elements));
+            }
+            Err(err) => assert!(matches!(err, DeserializationError::InvalidValue(_))),
+        }
+
+        match E::try_from(bytes) {
+            Ok(element) => {
+                assert_eq!(E::ELEMENT_BYTES, bytes.len());
+                assert_eq!(bytes, element.to_bytes().as_slice());
+            }
+            Err(err) => assert!(matches!(err, DeserializationError::InvalidValue(_))),
+        }
+
+        let mut reader = SliceReader::new(bytes);
+        let num_elements = bytes.len() / E::ELEMENT_BYTES;
+        if let Ok(elements) = E::read_batch_from(&mut reader, num_elements) {
+            assert_eq!(num_elements, elements.len());
+            assert_eq!(
+                &bytes[..num_elements * E::ELEMENT_BYTES],
+                E::elements_as_bytes(&elements)
+            );
+        }
+    }
+
+    /// Makes sure that batch serialization produces the same bytes as serializing elements one by
+    /// one.
+    fn check_write_batch_into<E: FieldElement>(elements: &[E]) {
+        let mut expected = Vec::new();
+        for element in elements {
+            element.write_into(&mut expected);
+        }
+
+        let mut actual = Vec::new();
+        E::write_batch_into(elements, &mut actual);
+        assert_eq!(expected, actual);
+        assert_eq!(elements.len() * E::ELEMENT_BYTES, actual.len());
+    }
+
+    /// Computes the product of (x - d) for all d in the `domain`.
+    fn vanishing_reference<B>(x: CubeExtension<B>, domain: &[B]) -> CubeExtension<B>
+    where
+        B: StarkField + ExtensibleField<3>,
+    {
+        domain
+            .iter()
+            .fold(CubeExtension::ONE, |acc, &d| acc * (x - d))
+    }
+
+    fn exp_reference(base: CubeExtension<BaseElement>, power: u64) -> CubeExtension<BaseElement> {
+        let mut r = CubeExtension::<BaseElement>::ONE;
+        for i in (0..64).rev() {
+            r = r * r;
+            if (power >> i) & 1 == 1 {
+                r *= base;
+            }
+        }
+        r
+    }
+    /// Checks that the multiplicative generator of the cubic extension of B has order p^3 - 1
+    /// given all prime factors of p^3 - 1.
+    fn check_generator_order<B: StarkField + ExtensibleField<3>>(factors: &[&str]) {
+        let p = BigUint::from_bytes_le(&B::get_modulus_le_bytes());
+        let order = p.pow(3) - 1u32;
+
+        // make sure the factorization is complete
+        let mut rest = order.clone();
+        for factor in factors {
+            let q: BigUint = factor.parse().unwrap();
+            while (&rest % &q) == BigUint::from(0u32) {
+                rest /= &q;
+            }
+        }
+        assert_eq!(BigUint::from(1u32), rest);
+
+        let g = CubeExtension::<B>::multiplicative_generator();
+        assert_eq!(CubeExtension::ONE, g.exp_limbs(&order.to_u64_digits()));
+        for factor in factors {
+            let q: BigUint = factor.parse().unwrap();
+            let cofactor = &order / q;
+            assert_ne!(CubeExtension::ONE, g.exp_limbs(&cofactor.to_u64_digits()));
+        }
+    }
+    /// Checks that coordinates of a cubic extension element over B are placed at consecutive
+    /// offsets without padding, and that the alignment of the element is the same as for B.
+    fn check_memory_layout<B: ExtensibleField<3>>() {
+        let size = mem::size_of::<B>();
+        assert_eq!(3 * size, mem::size_of::<CubeExtension<B>>());
+        assert_eq!(mem::align_of::<B>(), mem::align_of::<CubeExtension<B>>());
+
+        let value = CubeExtension::<B>::default();
+        let base = ptr::addr_of!(value) as usize;
+        assert_eq!(0, ptr::addr_of!(value.0) as usize - base);
+        assert_eq!(size, ptr::addr_of!(value.1) as usize - base);
+        assert_eq!(2 * size, ptr::addr_of!(value.2) as usize - base);
+
+        // coordinates of adjacent elements in a slice are contiguous as well
+        let values = [CubeExtension::<B>::default(); 2];
+        let base = values.as_ptr() as usize;
+        assert_eq!(3 * size, ptr::addr_of!(values[1].0) as usize - base);
+    }
+    /// Checks that [ExtensibleField::mul_base()] for the cubic extension of B is the same as
+    /// multiplication by the scalar lifted into the extension field.
+    fn check_mul_base<B: ExtensibleField<3>>() {
+        for _ in 0..100 {
+            let a: [B; 3] = rand_array();
+            let b: B = rand_value();
+            let expected = <B as ExtensibleField<3>>::mul(a, [b, B::ZERO, B::ZERO]);
+            assert_eq!(expected, <B as ExtensibleField<3>>::mul_base(a, b));
+        }
+    }
+
+    fn check_bits_round_trip<B: StarkField + ExtensibleField<3>>() {
+        let num_bits = 3 * B::MODULUS_BITS as usize;
+        let values: Vec<CubeExtension<B>> = rand_vector(32);
+        for value in values
+            .into_iter()
+            .chain([CubeExtension::ZERO, -CubeExtension::ONE])
+        {
+            let bits = value.to_bits();
+            assert_eq!(num_bits, bits.len());
+            assert_eq!(value, CubeExtension::from_bits(&bits).unwrap());
+        }
+    }
 }
@@ -9,4 +9,10 @@ pub use quadratic::QuadExtension;
 mod cubic;
 pub use cubic::CubeExtension;
 
-use super::{ExtensibleField, FieldElement};
+mod quartic;
+pub use quartic::QuarticExtension;
+
+mod sextic;
+pub use sextic::SexticExtension;
+
+use super::{ExtensibleField, ExtensionOf, FieldElement, MontgomeryField, StarkField};
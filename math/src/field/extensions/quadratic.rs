@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{ExtensibleField, FieldElement};
+use super::{ExtensibleField, ExtensionOf, FieldElement, StarkField};
 use core::{
     convert::TryFrom,
     fmt,
@@ -11,8 +11,8 @@ use core::{
     slice,
 };
 use utils::{
-    collections::Vec, string::ToString, AsBytes, ByteReader, ByteWriter, Deserializable,
-    DeserializationError, Randomizable, Serializable, SliceReader,
+    collections::Vec, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable, SliceReader,
 };
 
 // QUADRATIC EXTENSION FIELD
@@ -25,9 +25,9 @@ use utils::{
 /// elements.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
-pub struct QuadExtension<B: ExtensibleField<2>>(B, B);
+pub struct QuadExtension<B: StarkField + ExtensibleField<2>>(B, B);
 
-impl<B: ExtensibleField<2>> QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> QuadExtension<B> {
     /// Returns a new extension element instantiated from the provided base elements.
     pub fn new(a: B, b: B) -> Self {
         Self(a, b)
@@ -55,11 +55,12 @@ impl<B: ExtensibleField<2>> QuadExtension<B> {
     }
 }
 
-impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> FieldElement for QuadExtension<B> {
     type PositiveInteger = B::PositiveInteger;
     type BaseField = B;
 
     const ELEMENT_BYTES: usize = B::ELEMENT_BYTES * 2;
+    const EXTENSION_DEGREE: usize = 2;
     const IS_CANONICAL: bool = B::IS_CANONICAL;
     const ZERO: Self = Self(B::ZERO, B::ZERO);
     const ONE: Self = Self(B::ONE, B::ZERO);
@@ -107,10 +108,14 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
         let len = bytes.len() / Self::ELEMENT_BYTES;
 
         // make sure the bytes are aligned on the boundary consistent with base element alignment
-        if (p as usize) % Self::BaseField::ELEMENT_BYTES != 0 {
-            return Err(DeserializationError::InvalidValue(
-                "slice memory alignment is not valid for this field element type".to_string(),
-            ));
+        let alignment = Self::BaseField::ELEMENT_BYTES;
+        let offset = (p as usize) % alignment;
+        if offset != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "slice memory alignment is not valid for this field element type; expected \
+                alignment of {} bytes, but pointer offset modulo alignment was {} bytes",
+                alignment, offset,
+            )));
         }
 
         Ok(slice::from_raw_parts(p as *const Self, len))
@@ -127,17 +132,50 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
         let len = elements.len() * 2;
         unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
     }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        let ptr = elements.as_mut_ptr();
+        let len = elements.len() * 2;
+        unsafe { slice::from_raw_parts_mut(ptr as *mut Self::BaseField, len) }
+    }
+}
+
+impl<B: StarkField + ExtensibleField<2>> ExtensionOf<B> for QuadExtension<B> {
+    #[inline(always)]
+    fn base_degree() -> usize {
+        2
+    }
+
+    #[inline(always)]
+    fn from_base(b: B) -> Self {
+        Self(b, B::ZERO)
+    }
+
+    fn to_base_coords(&self) -> Vec<B> {
+        vec![self.0, self.1]
+    }
 }
 
-impl<B: ExtensibleField<2>> Randomizable for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Randomizable for QuadExtension<B> {
     const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
 
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::try_from(bytes).ok()
+        if bytes.len() != Self::VALUE_SIZE {
+            return None;
+        }
+        // each coordinate is drawn via the base field rather than by deserializing the whole
+        // buffer. for base fields which implement from_random_bytes() via try_from() (e.g., f64,
+        // f62, f128) this yields exactly the same elements as Self::try_from(bytes).ok(); but for
+        // fields which reduce random bytes instead of rejecting them (e.g., f61), deserializing the
+        // whole buffer would reject almost all attempts.
+        let mut coords = bytes.chunks_exact(B::ELEMENT_BYTES);
+        let a = B::from_random_bytes(coords.next()?)?;
+        let b = B::from_random_bytes(coords.next()?)?;
+        Some(Self(a, b))
     }
 }
 
-impl<B: ExtensibleField<2>> fmt::Display for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> fmt::Display for QuadExtension<B> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.0, self.1)
     }
@@ -146,7 +184,7 @@ impl<B: ExtensibleField<2>> fmt::Display for QuadExtension<B> {
 // OVERLOADED OPERATORS
 // ------------------------------------------------------------------------------------------------
 
-impl<B: ExtensibleField<2>> Add for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Add for QuadExtension<B> {
     type Output = Self;
 
     #[inline]
@@ -155,14 +193,14 @@ impl<B: ExtensibleField<2>> Add for QuadExtension<B> {
     }
 }
 
-impl<B: ExtensibleField<2>> AddAssign for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> AddAssign for QuadExtension<B> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs
     }
 }
 
-impl<B: ExtensibleField<2>> Sub for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Sub for QuadExtension<B> {
     type Output = Self;
 
     #[inline]
@@ -171,14 +209,14 @@ impl<B: ExtensibleField<2>> Sub for QuadExtension<B> {
     }
 }
 
-impl<B: ExtensibleField<2>> SubAssign for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> SubAssign for QuadExtension<B> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
-impl<B: ExtensibleField<2>> Mul for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Mul for QuadExtension<B> {
     type Output = Self;
 
     #[inline]
@@ -188,14 +226,14 @@ impl<B: ExtensibleField<2>> Mul for QuadExtension<B> {
     }
 }
 
-impl<B: ExtensibleField<2>> MulAssign for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> MulAssign for QuadExtension<B> {
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs
     }
 }
 
-impl<B: ExtensibleField<2>> Div for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Div for QuadExtension<B> {
     type Output = Self;
 
     #[inline]
@@ -205,14 +243,14 @@ impl<B: ExtensibleField<2>> Div for QuadExtension<B> {
     }
 }
 
-impl<B: ExtensibleField<2>> DivAssign for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> DivAssign for QuadExtension<B> {
     #[inline]
     fn div_assign(&mut self, rhs: Self) {
         *self = *self / rhs
     }
 }
 
-impl<B: ExtensibleField<2>> Neg for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Neg for QuadExtension<B> {
     type Output = Self;
 
     #[inline]
@@ -224,43 +262,43 @@ impl<B: ExtensibleField<2>> Neg for QuadExtension<B> {
 // TYPE CONVERSIONS
 // ------------------------------------------------------------------------------------------------
 
-impl<B: ExtensibleField<2>> From<B> for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> From<B> for QuadExtension<B> {
     fn from(value: B) -> Self {
         Self(value, B::ZERO)
     }
 }
 
-impl<B: ExtensibleField<2>> From<u128> for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> From<u128> for QuadExtension<B> {
     fn from(value: u128) -> Self {
         Self(B::from(value), B::ZERO)
     }
 }
 
-impl<B: ExtensibleField<2>> From<u64> for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> From<u64> for QuadExtension<B> {
     fn from(value: u64) -> Self {
         Self(B::from(value), B::ZERO)
     }
 }
 
-impl<B: ExtensibleField<2>> From<u32> for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> From<u32> for QuadExtension<B> {
     fn from(value: u32) -> Self {
         Self(B::from(value), B::ZERO)
     }
 }
 
-impl<B: ExtensibleField<2>> From<u16> for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> From<u16> for QuadExtension<B> {
     fn from(value: u16) -> Self {
         Self(B::from(value), B::ZERO)
     }
 }
 
-impl<B: ExtensibleField<2>> From<u8> for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> From<u8> for QuadExtension<B> {
     fn from(value: u8) -> Self {
         Self(B::from(value), B::ZERO)
     }
 }
 
-impl<'a, B: ExtensibleField<2>> TryFrom<&'a [u8]> for QuadExtension<B> {
+impl<'a, B: StarkField + ExtensibleField<2>> TryFrom<&'a [u8]> for QuadExtension<B> {
     type Error = DeserializationError;
 
     /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
@@ -285,7 +323,7 @@ impl<'a, B: ExtensibleField<2>> TryFrom<&'a [u8]> for QuadExtension<B> {
     }
 }
 
-impl<B: ExtensibleField<2>> AsBytes for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> AsBytes for QuadExtension<B> {
     fn as_bytes(&self) -> &[u8] {
         // TODO: take endianness into account
         let self_ptr: *const Self = self;
@@ -296,14 +334,14 @@ impl<B: ExtensibleField<2>> AsBytes for QuadExtension<B> {
 // SERIALIZATION / DESERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
-impl<B: ExtensibleField<2>> Serializable for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Serializable for QuadExtension<B> {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.0.write_into(target);
         self.1.write_into(target);
     }
 }
 
-impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
+impl<B: StarkField + ExtensibleField<2>> Deserializable for QuadExtension<B> {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let value0 = B::read_from(source)?;
         let value1 = B::read_from(source)?;
@@ -311,6 +349,89 @@ impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
     }
 }
 
+// CUBIC EXTENSION OF QUADRATIC EXTENSION
+// ================================================================================================
+
+/// Defines a cubic extension of the quadratic extension field, i.e., a degree 6 extension of the
+/// base field built as a tower.
+///
+/// The cubic extension is defined using the same irreducible polynomial as the cubic extension of
+/// the base field (as defined by the implementation of `ExtensibleField<3>` for `B`). A cubic
+/// polynomial which is irreducible over a field remains irreducible over its quadratic extension
+/// (since 2 and 3 are co-prime), and thus, this polynomial can be used to extend the quadratic
+/// extension field as well.
+impl<B> ExtensibleField<3> for QuadExtension<B>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+{
+    // the same polynomial as for the cubic extension of the base field, with its coefficients
+    // lifted into the quadratic extension
+    const IRREDUCIBLE_COEFFS: Option<[Self; 3]> =
+        match <B as ExtensibleField<3>>::IRREDUCIBLE_COEFFS {
+            Some(c) => Some([
+                Self(c[0], B::ZERO),
+                Self(c[1], B::ZERO),
+                Self(c[2], B::ZERO),
+            ]),
+            None => None,
+        };
+
+    #[inline(always)]
+    fn mul(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        // an element of the tower can be written as u + v * φ, where u and v are elements in the
+        // cubic extension of the base field, and φ is the root of the quadratic polynomial. thus,
+        // a product of two elements is computed using 3 multiplications in the cubic extension
+        // of the base field, and then φ^2 is reduced using the quadratic polynomial.
+        let (a_u, a_v) = split_cubic_coeffs(a);
+        let (b_u, b_v) = split_cubic_coeffs(b);
+
+        let uu = <B as ExtensibleField<3>>::mul(a_u, b_u);
+        let vv = <B as ExtensibleField<3>>::mul(a_v, b_v);
+        let uv =
+            <B as ExtensibleField<3>>::mul(add_cubic_coeffs(a_u, a_v), add_cubic_coeffs(b_u, b_v));
+
+        // φ^2 = φ2[0] + φ2[1] * φ
+        let phi2 = <B as ExtensibleField<2>>::mul([B::ZERO, B::ONE], [B::ZERO, B::ONE]);
+
+        let mut result = [Self::ZERO; 3];
+        for i in 0..3 {
+            let cross = uv[i] - uu[i] - vv[i];
+            result[i] = Self(uu[i] + vv[i] * phi2[0], cross + vv[i] * phi2[1]);
+        }
+        result
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 3]) -> [Self; 3] {
+        // the Frobenius automorphism relative to the quadratic extension is x -> x^(p^2). since
+        // coefficients in the quadratic extension are fixed by this map, it can be computed by
+        // applying the Frobenius automorphism of the cubic extension of the base field twice
+        // to the u and v parts separately.
+        let (u, v) = split_cubic_coeffs(x);
+        let u = <B as ExtensibleField<3>>::frobenius(<B as ExtensibleField<3>>::frobenius(u));
+        let v = <B as ExtensibleField<3>>::frobenius(<B as ExtensibleField<3>>::frobenius(v));
+        [Self(u[0], v[0]), Self(u[1], v[1]), Self(u[2], v[2])]
+    }
+
+    fn is_supported() -> bool {
+        <B as ExtensibleField<2>>::is_supported() && <B as ExtensibleField<3>>::is_supported()
+    }
+}
+
+/// Splits coefficients of an element in the cubic extension of the quadratic extension field into
+/// two elements in the cubic extension of the base field.
+#[inline(always)]
+fn split_cubic_coeffs<B: StarkField + ExtensibleField<2>>(
+    x: [QuadExtension<B>; 3],
+) -> ([B; 3], [B; 3]) {
+    ([x[0].0, x[1].0, x[2].0], [x[0].1, x[1].1, x[2].1])
+}
+
+#[inline(always)]
+fn add_cubic_coeffs<B: StarkField>(a: [B; 3], b: [B; 3]) -> [B; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
 // TESTS
 // ================================================================================================
 
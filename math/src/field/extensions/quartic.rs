@@ -0,0 +1,518 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{ExtensibleField, FieldElement, StarkField};
+use core::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+use utils::{
+    collections::Vec, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable, SliceReader,
+};
+
+// QUARTIC EXTENSION FIELD
+// ================================================================================================
+
+/// Represents an element in a quartic extension of a [StarkField](crate::StarkField).
+///
+/// The extension element is defined as α + β * φ + γ * φ^2 + δ * φ^3, where φ is a root of in
+/// irreducible polynomial defined by the implementation of the [ExtensibleField] trait, and α, β,
+/// γ, δ are base field elements.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct QuarticExtension<B: StarkField + ExtensibleField<4>>(B, B, B, B);
+
+impl<B: StarkField + ExtensibleField<4>> QuarticExtension<B> {
+    /// Returns a new extension element instantiated from the provided base elements.
+    pub fn new(a: B, b: B, c: B, d: B) -> Self {
+        Self(a, b, c, d)
+    }
+
+    /// Returns true if the base field specified by B type parameter supports quartic extensions.
+    pub fn is_supported() -> bool {
+        <B as ExtensibleField<4>>::is_supported()
+    }
+
+    /// Converts a vector of base elements into a vector of elements in a quartic extension field
+    /// by fusing four adjacent base elements together. The output vector is one quarter the
+    /// length of the source vector.
+    fn base_to_quartic_vector(source: Vec<B>) -> Vec<Self> {
+        debug_assert!(
+            source.len() % 4 == 0,
+            "source vector length must be divisible by four, but was {}",
+            source.len()
+        );
+        let mut v = core::mem::ManuallyDrop::new(source);
+        let p = v.as_mut_ptr();
+        let len = v.len() / 4;
+        let cap = v.capacity() / 4;
+        unsafe { Vec::from_raw_parts(p as *mut Self, len, cap) }
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> FieldElement for QuarticExtension<B> {
+    type PositiveInteger = B::PositiveInteger;
+    type BaseField = B;
+
+    const ELEMENT_BYTES: usize = B::ELEMENT_BYTES * 4;
+    const EXTENSION_DEGREE: usize = 4;
+    const IS_CANONICAL: bool = B::IS_CANONICAL;
+    const ZERO: Self = Self(B::ZERO, B::ZERO, B::ZERO, B::ZERO);
+    const ONE: Self = Self(B::ONE, B::ZERO, B::ZERO, B::ZERO);
+
+    #[inline]
+    fn inv(self) -> Self {
+        if self == Self::ZERO {
+            return self;
+        }
+
+        let x = [self.0, self.1, self.2, self.3];
+        let c1 = <B as ExtensibleField<4>>::frobenius(x);
+        let c2 = <B as ExtensibleField<4>>::frobenius(c1);
+        let c3 = <B as ExtensibleField<4>>::frobenius(c2);
+        let numerator =
+            <B as ExtensibleField<4>>::mul(<B as ExtensibleField<4>>::mul(c1, c2), c3);
+
+        let norm = <B as ExtensibleField<4>>::mul(x, numerator);
+        debug_assert_eq!(norm[1], B::ZERO, "norm must be in the base field");
+        debug_assert_eq!(norm[2], B::ZERO, "norm must be in the base field");
+        debug_assert_eq!(norm[3], B::ZERO, "norm must be in the base field");
+        let denom_inv = norm[0].inv();
+
+        Self(
+            numerator[0] * denom_inv,
+            numerator[1] * denom_inv,
+            numerator[2] * denom_inv,
+            numerator[3] * denom_inv,
+        )
+    }
+
+    #[inline]
+    fn conjugate(&self) -> Self {
+        let result = <B as ExtensibleField<4>>::frobenius([self.0, self.1, self.2, self.3]);
+        Self(result[0], result[1], result[2], result[3])
+    }
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * Self::ELEMENT_BYTES,
+            )
+        }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        // make sure the bytes are aligned on the boundary consistent with base element alignment
+        let alignment = Self::BaseField::ELEMENT_BYTES;
+        let offset = (p as usize) % alignment;
+        if offset != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "slice memory alignment is not valid for this field element type; expected \
+                alignment of {} bytes, but pointer offset modulo alignment was {} bytes",
+                alignment, offset,
+            )));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+
+    fn zeroed_vector(n: usize) -> Vec<Self> {
+        // get four times the number of base elements and re-interpret them as quartic field
+        // elements
+        let result = B::zeroed_vector(n * 4);
+        Self::base_to_quartic_vector(result)
+    }
+
+    fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        let ptr = elements.as_ptr();
+        let len = elements.len() * 4;
+        unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
+    }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        let ptr = elements.as_mut_ptr();
+        let len = elements.len() * 4;
+        unsafe { slice::from_raw_parts_mut(ptr as *mut Self::BaseField, len) }
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> Randomizable for QuarticExtension<B> {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::VALUE_SIZE {
+            return None;
+        }
+        // each coordinate is drawn via the base field; see the note in QuadExtension for why
+        // this matters for fields such as f61
+        let mut coords = bytes.chunks_exact(B::ELEMENT_BYTES);
+        let a = B::from_random_bytes(coords.next()?)?;
+        let b = B::from_random_bytes(coords.next()?)?;
+        let c = B::from_random_bytes(coords.next()?)?;
+        let d = B::from_random_bytes(coords.next()?)?;
+        Some(Self(a, b, c, d))
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> fmt::Display for QuarticExtension<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.0, self.1, self.2, self.3)
+    }
+}
+
+// OVERLOADED OPERATORS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: StarkField + ExtensibleField<4>> Add for QuarticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(
+            self.0 + rhs.0,
+            self.1 + rhs.1,
+            self.2 + rhs.2,
+            self.3 + rhs.3,
+        )
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> AddAssign for QuarticExtension<B> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> Sub for QuarticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(
+            self.0 - rhs.0,
+            self.1 - rhs.1,
+            self.2 - rhs.2,
+            self.3 - rhs.3,
+        )
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> SubAssign for QuarticExtension<B> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> Mul for QuarticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let result = <B as ExtensibleField<4>>::mul(
+            [self.0, self.1, self.2, self.3],
+            [rhs.0, rhs.1, rhs.2, rhs.3],
+        );
+        Self(result[0], result[1], result[2], result[3])
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> MulAssign for QuarticExtension<B> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> Div for QuarticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> DivAssign for QuarticExtension<B> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> Neg for QuarticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2, -self.3)
+    }
+}
+
+// TYPE CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: StarkField + ExtensibleField<4>> From<B> for QuarticExtension<B> {
+    fn from(value: B) -> Self {
+        Self(value, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> From<u128> for QuarticExtension<B> {
+    fn from(value: u128) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> From<u64> for QuarticExtension<B> {
+    fn from(value: u64) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> From<u32> for QuarticExtension<B> {
+    fn from(value: u32) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> From<u16> for QuarticExtension<B> {
+    fn from(value: u16) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> From<u8> for QuarticExtension<B> {
+    fn from(value: u8) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> TryFrom<&[u8]> for QuarticExtension<B> {
+    type Error = DeserializationError;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to be in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "not enough bytes for a full field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        if bytes.len() > Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "too many bytes for a field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        let mut reader = SliceReader::new(bytes);
+        Self::read_from(&mut reader)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> AsBytes for QuarticExtension<B> {
+    fn as_bytes(&self) -> &[u8] {
+        // TODO: take endianness into account
+        let self_ptr: *const Self = self;
+        unsafe { slice::from_raw_parts(self_ptr as *const u8, Self::ELEMENT_BYTES) }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl<B: StarkField + ExtensibleField<4>> Serializable for QuarticExtension<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+        self.1.write_into(target);
+        self.2.write_into(target);
+        self.3.write_into(target);
+    }
+}
+
+impl<B: StarkField + ExtensibleField<4>> Deserializable for QuarticExtension<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value0 = B::read_from(source)?;
+        let value1 = B::read_from(source)?;
+        let value2 = B::read_from(source)?;
+        let value3 = B::read_from(source)?;
+        Ok(Self(value0, value1, value2, value3))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{DeserializationError, FieldElement, QuarticExtension, Vec};
+    use crate::field::f64::BaseElement;
+    use rand_utils::rand_value;
+
+    // BASIC ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn add() {
+        // identity
+        let r: QuarticExtension<BaseElement> = rand_value();
+        assert_eq!(r, r + QuarticExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1: QuarticExtension<BaseElement> = rand_value();
+        let r2: QuarticExtension<BaseElement> = rand_value();
+
+        let expected = QuarticExtension(r1.0 + r2.0, r1.1 + r2.1, r1.2 + r2.2, r1.3 + r2.3);
+        assert_eq!(expected, r1 + r2);
+    }
+
+    #[test]
+    fn sub() {
+        // identity
+        let r: QuarticExtension<BaseElement> = rand_value();
+        assert_eq!(r, r - QuarticExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1: QuarticExtension<BaseElement> = rand_value();
+        let r2: QuarticExtension<BaseElement> = rand_value();
+
+        let expected = QuarticExtension(r1.0 - r2.0, r1.1 - r2.1, r1.2 - r2.2, r1.3 - r2.3);
+        assert_eq!(expected, r1 - r2);
+    }
+
+    // INITIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn zeroed_vector() {
+        let result = QuarticExtension::<BaseElement>::zeroed_vector(4);
+        assert_eq!(4, result.len());
+        for element in result.into_iter() {
+            assert_eq!(QuarticExtension::<BaseElement>::ZERO, element);
+        }
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn elements_as_bytes() {
+        let source = vec![
+            QuarticExtension(
+                BaseElement::new(1),
+                BaseElement::new(2),
+                BaseElement::new(3),
+                BaseElement::new(4),
+            ),
+            QuarticExtension(
+                BaseElement::new(5),
+                BaseElement::new(6),
+                BaseElement::new(7),
+                BaseElement::new(8),
+            ),
+        ];
+
+        let expected: Vec<u8> = vec![
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0,
+            0, 0, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(
+            expected,
+            QuarticExtension::<BaseElement>::elements_as_bytes(&source)
+        );
+    }
+
+    #[test]
+    fn bytes_as_elements() {
+        let bytes: Vec<u8> = vec![
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0,
+            0, 0, 0, 0, 0, 0, 9,
+        ];
+
+        let expected = vec![
+            QuarticExtension(
+                BaseElement::new(1),
+                BaseElement::new(2),
+                BaseElement::new(3),
+                BaseElement::new(4),
+            ),
+            QuarticExtension(
+                BaseElement::new(5),
+                BaseElement::new(6),
+                BaseElement::new(7),
+                BaseElement::new(8),
+            ),
+        ];
+
+        let result = unsafe { QuarticExtension::<BaseElement>::bytes_as_elements(&bytes[..64]) };
+        assert!(result.is_ok());
+        assert_eq!(expected, result.unwrap());
+
+        let result = unsafe { QuarticExtension::<BaseElement>::bytes_as_elements(&bytes) };
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+        let result = unsafe { QuarticExtension::<BaseElement>::bytes_as_elements(&bytes[1..]) };
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+    }
+
+    // UTILITIES
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn as_base_elements() {
+        let elements = vec![
+            QuarticExtension(
+                BaseElement::new(1),
+                BaseElement::new(2),
+                BaseElement::new(3),
+                BaseElement::new(4),
+            ),
+            QuarticExtension(
+                BaseElement::new(5),
+                BaseElement::new(6),
+                BaseElement::new(7),
+                BaseElement::new(8),
+            ),
+        ];
+
+        let expected = vec![
+            BaseElement::new(1),
+            BaseElement::new(2),
+            BaseElement::new(3),
+            BaseElement::new(4),
+            BaseElement::new(5),
+            BaseElement::new(6),
+            BaseElement::new(7),
+            BaseElement::new(8),
+        ];
+
+        assert_eq!(
+            expected,
+            QuarticExtension::<BaseElement>::as_base_elements(&elements)
+        );
+    }
+}
@@ -0,0 +1,623 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{ExtensibleField, FieldElement, StarkField};
+use core::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+use utils::{
+    collections::Vec, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable, SliceReader,
+};
+
+// SEXTIC EXTENSION FIELD
+// ================================================================================================
+
+/// Represents an element in a sextic extension of a [StarkField](crate::StarkField).
+///
+/// The extension element is defined as a_0 + a_1 * φ + a_2 * φ^2 + ... + a_5 * φ^5, where φ is a
+/// root of in irreducible polynomial defined by the implementation of the [ExtensibleField] trait,
+/// and a_0, ..., a_5 are base field elements.
+///
+/// Unlike a `CubeExtension<QuadExtension<B>>` tower, this is a direct degree 6 extension of the
+/// base field, and thus, the base field can use a multiplication formula tailored to its
+/// irreducible polynomial.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct SexticExtension<B: StarkField + ExtensibleField<6>>(B, B, B, B, B, B);
+
+impl<B: StarkField + ExtensibleField<6>> SexticExtension<B> {
+    /// Returns a new extension element instantiated from the provided base elements.
+    pub fn new(a0: B, a1: B, a2: B, a3: B, a4: B, a5: B) -> Self {
+        Self(a0, a1, a2, a3, a4, a5)
+    }
+
+    /// Returns an array of base elements comprising this extension element.
+    pub fn to_array(self) -> [B; 6] {
+        [self.0, self.1, self.2, self.3, self.4, self.5]
+    }
+
+    /// Returns true if the base field specified by B type parameter supports sextic extensions.
+    pub fn is_supported() -> bool {
+        <B as ExtensibleField<6>>::is_supported()
+    }
+
+    /// Returns the norm of this element relative to the base field.
+    ///
+    /// The norm is computed as a product of this element and its five Galois conjugates, and
+    /// thus always lies in the base field.
+    pub fn norm(&self) -> B {
+        self.norm_with_numerator().1
+    }
+
+    /// Computes the norm of this element together with the product of its five Galois conjugates.
+    ///
+    /// The product of the conjugates is the numerator of the inverse of this element (i.e., the
+    /// inverse is obtained by dividing it by the norm).
+    fn norm_with_numerator(&self) -> ([B; 6], B) {
+        let x = self.to_array();
+        let mut conjugate = x;
+        let mut numerator = [B::ONE, B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO];
+        for _ in 0..5 {
+            conjugate = <B as ExtensibleField<6>>::frobenius(conjugate);
+            numerator = <B as ExtensibleField<6>>::mul(numerator, conjugate);
+        }
+
+        let norm = <B as ExtensibleField<6>>::mul(x, numerator);
+        for coeff in norm.iter().skip(1) {
+            debug_assert_eq!(*coeff, B::ZERO, "norm must be in the base field");
+        }
+        (numerator, norm[0])
+    }
+
+    /// Converts a vector of base elements into a vector of elements in a sextic extension field
+    /// by fusing six adjacent base elements together. The output vector is one sixth the length
+    /// of the source vector.
+    fn base_to_sextic_vector(source: Vec<B>) -> Vec<Self> {
+        debug_assert!(
+            source.len() % 6 == 0,
+            "source vector length must be divisible by six, but was {}",
+            source.len()
+        );
+        debug_assert!(
+            source.capacity() % 6 == 0,
+            "source vector capacity must be divisible by six, but was {}",
+            source.capacity()
+        );
+        let mut v = core::mem::ManuallyDrop::new(source);
+        let p = v.as_mut_ptr();
+        let len = v.len() / 6;
+        let cap = v.capacity() / 6;
+        unsafe { Vec::from_raw_parts(p as *mut Self, len, cap) }
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> FieldElement for SexticExtension<B> {
+    type PositiveInteger = B::PositiveInteger;
+    type BaseField = B;
+
+    const ELEMENT_BYTES: usize = B::ELEMENT_BYTES * 6;
+    const EXTENSION_DEGREE: usize = 6;
+    const IS_CANONICAL: bool = B::IS_CANONICAL;
+    const ZERO: Self = Self(B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO);
+    const ONE: Self = Self(B::ONE, B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO);
+
+    #[inline]
+    fn inv(self) -> Self {
+        if self == Self::ZERO {
+            return self;
+        }
+
+        // the numerator is the product of the five conjugates obtained by applying the frobenius
+        // automorphism repeatedly, and the norm is obtained by multiplying it by this element
+        let (numerator, norm) = self.norm_with_numerator();
+        let denom_inv = norm.inv();
+
+        Self(
+            numerator[0] * denom_inv,
+            numerator[1] * denom_inv,
+            numerator[2] * denom_inv,
+            numerator[3] * denom_inv,
+            numerator[4] * denom_inv,
+            numerator[5] * denom_inv,
+        )
+    }
+
+    #[inline]
+    fn conjugate(&self) -> Self {
+        let result = <B as ExtensibleField<6>>::frobenius(self.to_array());
+        Self::from(result)
+    }
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * Self::ELEMENT_BYTES,
+            )
+        }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        // make sure the bytes are aligned on the boundary consistent with base element alignment
+        let alignment = Self::BaseField::ELEMENT_BYTES;
+        let offset = (p as usize) % alignment;
+        if offset != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "slice memory alignment is not valid for this field element type; expected \
+                alignment of {} bytes, but pointer offset modulo alignment was {} bytes",
+                alignment, offset,
+            )));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+
+    fn zeroed_vector(n: usize) -> Vec<Self> {
+        // get six times the number of base elements and re-interpret them as sextic field
+        // elements
+        let result = B::zeroed_vector(n * 6);
+        Self::base_to_sextic_vector(result)
+    }
+
+    fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        let ptr = elements.as_ptr();
+        let len = elements.len() * 6;
+        unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
+    }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        let ptr = elements.as_mut_ptr();
+        let len = elements.len() * 6;
+        unsafe { slice::from_raw_parts_mut(ptr as *mut Self::BaseField, len) }
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> Randomizable for SexticExtension<B> {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::VALUE_SIZE {
+            return None;
+        }
+        // each coordinate is drawn via the base field; see the note in QuadExtension for why
+        // this matters for fields such as f61
+        let mut coords = bytes.chunks_exact(B::ELEMENT_BYTES);
+        let a = B::from_random_bytes(coords.next()?)?;
+        let b = B::from_random_bytes(coords.next()?)?;
+        let c = B::from_random_bytes(coords.next()?)?;
+        let d = B::from_random_bytes(coords.next()?)?;
+        let e = B::from_random_bytes(coords.next()?)?;
+        let f = B::from_random_bytes(coords.next()?)?;
+        Some(Self(a, b, c, d, e, f))
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> fmt::Display for SexticExtension<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}, {}, {}, {}, {}, {})",
+            self.0, self.1, self.2, self.3, self.4, self.5
+        )
+    }
+}
+
+// OVERLOADED OPERATORS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: StarkField + ExtensibleField<6>> Add for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(
+            self.0 + rhs.0,
+            self.1 + rhs.1,
+            self.2 + rhs.2,
+            self.3 + rhs.3,
+            self.4 + rhs.4,
+            self.5 + rhs.5,
+        )
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> AddAssign for SexticExtension<B> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> Sub for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(
+            self.0 - rhs.0,
+            self.1 - rhs.1,
+            self.2 - rhs.2,
+            self.3 - rhs.3,
+            self.4 - rhs.4,
+            self.5 - rhs.5,
+        )
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> SubAssign for SexticExtension<B> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> Mul for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let result = <B as ExtensibleField<6>>::mul(self.to_array(), rhs.to_array());
+        Self::from(result)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> MulAssign for SexticExtension<B> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> Div for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> DivAssign for SexticExtension<B> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> Neg for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2, -self.3, -self.4, -self.5)
+    }
+}
+
+// TYPE CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: StarkField + ExtensibleField<6>> From<B> for SexticExtension<B> {
+    fn from(value: B) -> Self {
+        Self(value, B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> From<[B; 6]> for SexticExtension<B> {
+    fn from(value: [B; 6]) -> Self {
+        Self(value[0], value[1], value[2], value[3], value[4], value[5])
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> From<u128> for SexticExtension<B> {
+    fn from(value: u128) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> From<u64> for SexticExtension<B> {
+    fn from(value: u64) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> From<u32> for SexticExtension<B> {
+    fn from(value: u32) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> From<u16> for SexticExtension<B> {
+    fn from(value: u16) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> From<u8> for SexticExtension<B> {
+    fn from(value: u8) -> Self {
+        Self(B::from(value), B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> TryFrom<&[u8]> for SexticExtension<B> {
+    type Error = DeserializationError;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to be in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "not enough bytes for a full field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        if bytes.len() > Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "too many bytes for a field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        let mut reader = SliceReader::new(bytes);
+        Self::read_from(&mut reader)
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> AsBytes for SexticExtension<B> {
+    fn as_bytes(&self) -> &[u8] {
+        Self::elements_as_bytes(slice::from_ref(self))
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl<B: StarkField + ExtensibleField<6>> Serializable for SexticExtension<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+        self.1.write_into(target);
+        self.2.write_into(target);
+        self.3.write_into(target);
+        self.4.write_into(target);
+        self.5.write_into(target);
+    }
+}
+
+impl<B: StarkField + ExtensibleField<6>> Deserializable for SexticExtension<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value0 = B::read_from(source)?;
+        let value1 = B::read_from(source)?;
+        let value2 = B::read_from(source)?;
+        let value3 = B::read_from(source)?;
+        let value4 = B::read_from(source)?;
+        let value5 = B::read_from(source)?;
+        Ok(Self(value0, value1, value2, value3, value4, value5))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{DeserializationError, FieldElement, SexticExtension, Vec};
+    use crate::field::f64::BaseElement;
+    use core::convert::TryFrom;
+    use rand_utils::rand_array;
+    use utils::{AsBytes, Deserializable, Serializable, SliceReader};
+
+    // BASIC ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn add() {
+        // identity
+        let r = rand_sextic();
+        assert_eq!(r, r + SexticExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1 = rand_sextic();
+        let r2 = rand_sextic();
+
+        let expected = SexticExtension(
+            r1.0 + r2.0,
+            r1.1 + r2.1,
+            r1.2 + r2.2,
+            r1.3 + r2.3,
+            r1.4 + r2.4,
+            r1.5 + r2.5,
+        );
+        assert_eq!(expected, r1 + r2);
+    }
+
+    #[test]
+    fn sub() {
+        // identity
+        let r = rand_sextic();
+        assert_eq!(r, r - SexticExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1 = rand_sextic();
+        let r2 = rand_sextic();
+
+        let expected = SexticExtension(
+            r1.0 - r2.0,
+            r1.1 - r2.1,
+            r1.2 - r2.2,
+            r1.3 - r2.3,
+            r1.4 - r2.4,
+            r1.5 - r2.5,
+        );
+        assert_eq!(expected, r1 - r2);
+    }
+
+    #[test]
+    fn field_axioms() {
+        let zero = SexticExtension::<BaseElement>::ZERO;
+        let one = SexticExtension::<BaseElement>::ONE;
+
+        for _ in 0..20 {
+            let a = rand_sextic();
+            let b = rand_sextic();
+            let c = rand_sextic();
+
+            // commutativity and associativity
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a + b) + c, a + (b + c));
+            assert_eq!((a * b) * c, a * (b * c));
+
+            // distributivity
+            assert_eq!(a * (b + c), a * b + a * c);
+
+            // identities and inverses
+            assert_eq!(a, a * one);
+            assert_eq!(zero, a * zero);
+            assert_eq!(zero, a + (-a));
+            assert_eq!(one, a * a.inv());
+            assert_eq!(a, (a / b) * b);
+        }
+
+        assert_eq!(zero, zero.inv());
+        assert_eq!(one, one.inv());
+    }
+
+    #[test]
+    fn conjugate_and_norm() {
+        // applying frobenius automorphism six times must result in the original element
+        let a = rand_sextic();
+        let mut c = a;
+        for _ in 0..5 {
+            c = c.conjugate();
+            assert_ne!(a, c);
+        }
+        assert_eq!(a, c.conjugate());
+
+        // norm must lie in the base field and be multiplicative
+        let b = rand_sextic();
+        let norm = a.norm();
+        let mut expected = a;
+        let mut c = a;
+        for _ in 0..5 {
+            c = c.conjugate();
+            expected *= c;
+        }
+        assert_eq!(SexticExtension::from(norm), expected);
+        assert_eq!(a.norm() * b.norm(), (a * b).norm());
+    }
+
+    // INITIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn zeroed_vector() {
+        let result = SexticExtension::<BaseElement>::zeroed_vector(4);
+        assert_eq!(4, result.len());
+        for element in result.into_iter() {
+            assert_eq!(SexticExtension::<BaseElement>::ZERO, element);
+        }
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn serialization() {
+        let a = rand_sextic();
+        let bytes = a.to_bytes();
+        assert_eq!(SexticExtension::<BaseElement>::ELEMENT_BYTES, bytes.len());
+        assert_eq!(bytes, a.as_bytes());
+        assert_eq!(a, SexticExtension::try_from(&bytes[..]).unwrap());
+
+        let elements = vec![a, rand_sextic()];
+        let mut bytes = Vec::new();
+        SexticExtension::write_batch_into(&elements, &mut bytes);
+        let mut reader = SliceReader::new(&bytes);
+        let result = SexticExtension::<BaseElement>::read_batch_from(&mut reader, 2).unwrap();
+        assert_eq!(elements, result);
+
+        let result = SexticExtension::<BaseElement>::try_from(&bytes[..47]);
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+    }
+
+    #[test]
+    fn elements_as_bytes() {
+        let source = vec![
+            SexticExtension::from([1u8, 2, 3, 4, 5, 6].map(BaseElement::from)),
+            SexticExtension::from([7u8, 8, 9, 10, 11, 12].map(BaseElement::from)),
+        ];
+
+        let expected = (1..=12u8)
+            .flat_map(|i| [i, 0, 0, 0, 0, 0, 0, 0])
+            .collect::<Vec<u8>>();
+
+        assert_eq!(
+            expected,
+            SexticExtension::<BaseElement>::elements_as_bytes(&source)
+        );
+    }
+
+    #[test]
+    fn bytes_as_elements() {
+        let mut bytes = (1..=12u8)
+            .flat_map(|i| [i, 0, 0, 0, 0, 0, 0, 0])
+            .collect::<Vec<u8>>();
+        bytes.push(13);
+
+        let expected = vec![
+            SexticExtension::from([1u8, 2, 3, 4, 5, 6].map(BaseElement::from)),
+            SexticExtension::from([7u8, 8, 9, 10, 11, 12].map(BaseElement::from)),
+        ];
+
+        let result = unsafe { SexticExtension::<BaseElement>::bytes_as_elements(&bytes[..96]) };
+        assert!(result.is_ok());
+        assert_eq!(expected, result.unwrap());
+
+        let result = unsafe { SexticExtension::<BaseElement>::bytes_as_elements(&bytes) };
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+        let result = unsafe { SexticExtension::<BaseElement>::bytes_as_elements(&bytes[1..]) };
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+    }
+
+    // UTILITIES
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn as_base_elements() {
+        let elements = vec![rand_sextic(), rand_sextic()];
+
+        let mut expected = elements[0].to_array().to_vec();
+        expected.extend_from_slice(&elements[1].to_array());
+
+        assert_eq!(
+            expected,
+            SexticExtension::<BaseElement>::as_base_elements(&elements)
+        );
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn rand_sextic() -> SexticExtension<BaseElement> {
+        // 48-byte sextic elements are too large to be drawn by rand_value() directly
+        SexticExtension::from(rand_array::<BaseElement, 6>())
+    }
+}
@@ -127,6 +127,10 @@ impl FieldElement for BaseElement {
     fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
         elements
     }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        elements
+    }
 }
 
 impl StarkField for BaseElement {
@@ -159,6 +163,20 @@ impl StarkField for BaseElement {
     fn as_int(&self) -> Self::PositiveInteger {
         self.0
     }
+
+    #[inline]
+    fn select(a: Self, b: Self, choice: bool) -> Self {
+        // mask is all ones when choice is true and all zeros otherwise
+        let mask = 0u128.wrapping_sub(choice as u128);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        // internal values are always canonical; diff is ZERO only if the values are the same
+        let diff = self.0 ^ other.0;
+        (diff | diff.wrapping_neg()) >> 127 == 0
+    }
 }
 
 impl Randomizable for BaseElement {
@@ -249,6 +267,8 @@ impl Neg for BaseElement {
 /// x - 1. Thus, an extension element is defined as α + β * φ, where φ is a root of this polynomial,
 /// and α and β are base field elements.
 impl ExtensibleField<2> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 2]> = Some([BaseElement::new(1), BaseElement::new(1)]);
+
     #[inline(always)]
     fn mul(a: [Self; 2], b: [Self; 2]) -> [Self; 2] {
         let z = a[0] * b[0];
@@ -381,6 +401,28 @@ impl Deserializable for BaseElement {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for BaseElement {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // always serialize the canonical representation of the element
+        serializer.serialize_u128(self.as_int())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BaseElement {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <u128 as serde::Deserialize>::deserialize(deserializer)?;
+        if value >= M {
+            return Err(serde::de::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(BaseElement(value))
+    }
+}
+
 // FINITE FIELD ARITHMETIC
 // ================================================================================================
 
@@ -7,6 +7,7 @@ use super::{
     AsBytes, BaseElement, ByteReader, Deserializable, DeserializationError, FieldElement,
     StarkField, M,
 };
+use crate::field::{CubeExtension, ExtensibleField, QuadExtension};
 use core::convert::TryFrom;
 use num_bigint::BigUint;
 use rand_utils::{rand_value, rand_vector};
@@ -244,6 +245,26 @@ fn zeroed_vector() {
     }
 }
 
+#[test]
+fn extension_degree() {
+    const _: () = assert!(BaseElement::EXTENSION_DEGREE == 1);
+    const _: () = assert!(QuadExtension::<BaseElement>::EXTENSION_DEGREE == 2);
+    const _: () =
+        assert!(QuadExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 2);
+    const _: () = assert!(CubeExtension::<BaseElement>::EXTENSION_DEGREE == 3);
+    const _: () =
+        assert!(CubeExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 3);
+}
+
+#[test]
+fn cubic_extension_is_not_supported() {
+    assert!(!CubeExtension::<BaseElement>::is_supported());
+    // no irreducible polynomial is defined for the cubic extension, and thus, none is defined
+    // for the cubic extension of the quadratic extension either
+    assert!(<BaseElement as ExtensibleField<3>>::IRREDUCIBLE_COEFFS.is_none());
+    assert!(<QuadExtension<BaseElement> as ExtensibleField<3>>::IRREDUCIBLE_COEFFS.is_none());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
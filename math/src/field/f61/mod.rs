@@ -0,0 +1,567 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! An implementation of a 61-bit prime field with the Mersenne modulus $2^{61} - 1$.
+//!
+//! Since the modulus is a Mersenne prime, modular reduction can be performed using only shifts
+//! and additions. However, the multiplicative group of this field has two-adicity of only $1$,
+//! and thus, the field is not suitable for FFT-based polynomial arithmetic over large domains.
+//! The field is intended primarily for experimentation with fast reductions.
+//!
+//! Internally, the values are stored in the range $[0, 2^{61} - 1)$ using `u64` as the backing
+//! type.
+
+use super::{
+    traits::{FieldElement, StarkField},
+    ExtensibleField,
+};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt::{Debug, Display, Formatter},
+    mem,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+use utils::{
+    collections::Vec, string::ToString, AsBytes, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Randomizable, Serializable,
+};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+// Field modulus = 2^61 - 1
+const M: u64 = 0x1FFFFFFFFFFFFFFF;
+
+// Number of bits in the field modulus
+const M_BITS: u32 = 61;
+
+/// Number of bytes needed to represent field element
+const ELEMENT_BYTES: usize = core::mem::size_of::<u64>();
+
+// FIELD ELEMENT
+// ================================================================================================
+
+/// Represents base field element in the field.
+///
+/// Internal values are stored in the range [0, 2^61 - 1). The backing type is `u64`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct BaseElement(u64);
+
+impl BaseElement {
+    /// Creates a new field element from the provided `value`. If the value is greater than or
+    /// equal to the field modulus, modular reduction is silently performed.
+    pub const fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+}
+
+impl FieldElement for BaseElement {
+    type PositiveInteger = u64;
+    type BaseField = Self;
+
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    const ELEMENT_BYTES: usize = ELEMENT_BYTES;
+    const IS_CANONICAL: bool = true;
+
+    #[inline]
+    fn exp(self, power: Self::PositiveInteger) -> Self {
+        let mut b = self;
+
+        if power == 0 {
+            return Self::ONE;
+        } else if b == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        let mut r = if power & 1 == 1 { b } else { Self::ONE };
+        for i in 1..64 - power.leading_zeros() {
+            b = b.square();
+            if (power >> i) & 1 == 1 {
+                r *= b;
+            }
+        }
+
+        r
+    }
+
+    #[inline]
+    fn inv(self) -> Self {
+        // by Fermat's little theorem, base^(M - 2) is the inverse of base; for ZERO this yields
+        // ZERO as well
+        self.exp(M - 2)
+    }
+
+    fn conjugate(&self) -> Self {
+        Self(self.0)
+    }
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        // TODO: take endianness into account
+        let p = elements.as_ptr();
+        let len = elements.len() * Self::ELEMENT_BYTES;
+        unsafe { slice::from_raw_parts(p as *const u8, len) }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        if (p as usize) % mem::align_of::<u64>() != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+
+    fn zeroed_vector(n: usize) -> Vec<Self> {
+        // this uses a specialized vector initialization code which requests zero-filled memory
+        // from the OS; unfortunately, this works only for built-in types and we can't use
+        // Self::ZERO here as much less efficient initialization procedure will be invoked.
+        // We also use u64 to make sure the memory is aligned correctly for our element size.
+        let result = vec![0u64; n];
+
+        // translate a zero-filled vector of u64s into a vector of base field elements
+        let mut v = core::mem::ManuallyDrop::new(result);
+        let p = v.as_mut_ptr();
+        let len = v.len();
+        let cap = v.capacity();
+        unsafe { Vec::from_raw_parts(p as *mut Self, len, cap) }
+    }
+
+    #[inline]
+    fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        elements
+    }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        elements
+    }
+}
+
+impl StarkField for BaseElement {
+    /// sage: MODULUS = 2^61 - 1 \
+    /// sage: GF(MODULUS).is_prime_field() \
+    /// True \
+    /// sage: GF(MODULUS).order() \
+    /// 2305843009213693951
+    const MODULUS: Self::PositiveInteger = M;
+    const MODULUS_BITS: u32 = M_BITS;
+
+    /// sage: GF(MODULUS).primitive_element() \
+    /// 37
+    const GENERATOR: Self = Self::new(37);
+
+    /// sage: is_odd((MODULUS - 1) / 2) \
+    /// True
+    const TWO_ADICITY: u32 = 1;
+
+    /// sage: k = (MODULUS - 1) / 2 \
+    /// sage: GF(MODULUS).primitive_element()^k \
+    /// 2305843009213693950
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::new(M - 1);
+
+    fn get_modulus_le_bytes() -> Vec<u8> {
+        M.to_le_bytes().to_vec()
+    }
+
+    #[inline]
+    fn as_int(&self) -> Self::PositiveInteger {
+        self.0
+    }
+
+    #[inline]
+    fn select(a: Self, b: Self, choice: bool) -> Self {
+        // mask is all ones when choice is true and all zeros otherwise
+        let mask = 0u64.wrapping_sub(choice as u64);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        // internal values are always canonical; diff is ZERO only if the values are the same
+        let diff = self.0 ^ other.0;
+        (diff | diff.wrapping_neg()) >> 63 == 0
+    }
+}
+
+impl Randomizable for BaseElement {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        // the three most significant bits are discarded; otherwise, 7 out of 8 random values
+        // would be rejected. the remaining 61 bits are uniformly distributed in [0, 2^61), and
+        // thus, only the value equal to M needs to be rejected.
+        let bytes: [u8; ELEMENT_BYTES] = bytes.try_into().ok()?;
+        let value = u64::from_le_bytes(bytes) & M;
+        if value == M {
+            None
+        } else {
+            Some(Self(value))
+        }
+    }
+}
+
+impl Display for BaseElement {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl Add for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        // both values are smaller than 2^61, and thus, the sum cannot overflow
+        let z = self.0 + rhs.0;
+        Self(if z >= M { z - M } else { z })
+    }
+}
+
+impl AddAssign for BaseElement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        let (result, under) = self.0.overflowing_sub(rhs.0);
+        Self(result.wrapping_add(M * (under as u64)))
+    }
+}
+
+impl SubAssign for BaseElement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let z = (self.0 as u128) * (rhs.0 as u128);
+        Self(mod_reduce(z))
+    }
+}
+
+impl MulAssign for BaseElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Div for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for BaseElement {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl Neg for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            Self::ZERO
+        } else {
+            Self(M - self.0)
+        }
+    }
+}
+
+// QUADRATIC EXTENSION
+// ================================================================================================
+
+/// Defines a quadratic extension of the base field over an irreducible polynomial x<sup>2</sup> +
+/// 1 (the polynomial is irreducible because the field modulus is 3 mod 4). Thus, an extension
+/// element is defined as α + β * φ, where φ is a root of this polynomial, and α and β are base
+/// field elements.
+impl ExtensibleField<2> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 2]> =
+        Some([BaseElement::new(M - 1), BaseElement::new(0)]);
+
+    #[inline(always)]
+    fn mul(a: [Self; 2], b: [Self; 2]) -> [Self; 2] {
+        // performs multiplication in the extension field using 3 multiplications, 3 additions,
+        // and 3 subtractions in the base field.
+        let a0b0 = a[0] * b[0];
+        let a1b1 = a[1] * b[1];
+        [a0b0 - a1b1, (a[0] + a[1]) * (b[0] + b[1]) - a0b0 - a1b1]
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 2]) -> [Self; 2] {
+        // φ^p = -φ since p = 3 mod 4
+        [x[0], -x[1]]
+    }
+}
+
+// CUBIC EXTENSION
+// ================================================================================================
+
+/// Defines a cubic extension of the base field over an irreducible polynomial x<sup>3</sup> - 5
+/// (the polynomial is irreducible because the field modulus is 1 mod 3 and 5 is not a cube in the
+/// base field). Thus, an extension element is defined as α + β * φ + γ * φ^2, where φ is a root of
+/// this polynomial, and α, β and γ are base field elements.
+impl ExtensibleField<3> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(5),
+        BaseElement::new(0),
+        BaseElement::new(0),
+    ]);
+
+    /// 4 + φ is a generator of the multiplicative group of order p^3 - 1
+    const GENERATOR_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(4),
+        BaseElement::new(1),
+        BaseElement::new(0),
+    ]);
+
+    #[inline(always)]
+    fn mul(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        // performs schoolbook multiplication in the extension field using 9 multiplications in
+        // the base field; since φ^3 = 5, all terms of degree 3 and higher are folded back into
+        // lower degree terms by multiplying them by 5.
+        let w = BaseElement::new(5);
+        [
+            a[0] * b[0] + w * (a[1] * b[2] + a[2] * b[1]),
+            a[0] * b[1] + a[1] * b[0] + w * (a[2] * b[2]),
+            a[0] * b[2] + a[1] * b[1] + a[2] * b[0],
+        ]
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 3]) -> [Self; 3] {
+        // φ^p = φ * 5^((p - 1) / 3); thus, the i-th coordinate is multiplied by 5^(i * (p - 1) / 3)
+        // coefficients were computed using SageMath
+        [
+            x[0],
+            BaseElement::new(1669582390241348315) * x[1],
+            BaseElement::new(636260618972345635) * x[2],
+        ]
+    }
+
+    #[inline(always)]
+    fn frobenius_squared(x: [Self; 3]) -> [Self; 3] {
+        // the i-th coordinate is multiplied by 5^(2 * i * (p - 1) / 3); since 5^((p - 1) / 3) is
+        // a cube root of unity, the two non-trivial coefficients of frobenius are swapped
+        [
+            x[0],
+            BaseElement::new(636260618972345635) * x[1],
+            BaseElement::new(1669582390241348315) * x[2],
+        ]
+    }
+
+    fn sqrt_params() -> Option<(u32, &'static [u64])> {
+        // the order of the multiplicative group is p^3 - 1 = (p - 1) * (p^2 + p + 1); since
+        // p^2 + p + 1 is odd, two-adicity of the group is the same as for the base field.
+        // the cofactor was computed using SageMath
+        Some((
+            1,
+            &[3458764513820540927, 18014398509481984000, 18014398509481983],
+        ))
+    }
+}
+
+// TYPE CONVERSIONS
+// ================================================================================================
+
+impl From<u128> for BaseElement {
+    /// Converts a 128-bit value into a field element. If the value is greater than or equal to
+    /// the field modulus, modular reduction is silently performed.
+    fn from(value: u128) -> Self {
+        Self(mod_reduce(value))
+    }
+}
+
+impl From<u64> for BaseElement {
+    /// Converts a 64-bit value into a field element. If the value is greater than or equal to
+    /// the field modulus, modular reduction is silently performed.
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<u32> for BaseElement {
+    /// Converts a 32-bit value into a field element.
+    fn from(value: u32) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u16> for BaseElement {
+    /// Converts a 16-bit value into a field element.
+    fn from(value: u16) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u8> for BaseElement {
+    /// Converts an 8-bit value into a field element.
+    fn from(value: u8) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<[u8; 8]> for BaseElement {
+    /// Converts the value encoded in an array of 8 bytes into a field element. The bytes are
+    /// assumed to encode the element in the canonical representation in little-endian byte order.
+    /// If the value is greater than or equal to the field modulus, modular reduction is silently
+    /// performed.
+    fn from(bytes: [u8; 8]) -> Self {
+        let value = u64::from_le_bytes(bytes);
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&[u8]> for BaseElement {
+    type Error = DeserializationError;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to encode the element in the canonical
+    /// representation in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "not enough bytes for a full field element; expected {} bytes, but was {} bytes",
+                ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        if bytes.len() > ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "too many bytes for a field element; expected {} bytes, but was {} bytes",
+                ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        let value = bytes
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|error| DeserializationError::UnknownError(format!("{}", error)))?;
+        if value >= M {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl AsBytes for BaseElement {
+    fn as_bytes(&self) -> &[u8] {
+        // TODO: take endianness into account
+        let self_ptr: *const BaseElement = self;
+        unsafe { slice::from_raw_parts(self_ptr as *const u8, ELEMENT_BYTES) }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for BaseElement {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(&self.0.to_le_bytes());
+    }
+}
+
+impl Deserializable for BaseElement {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value = source.read_u64()?;
+        if value >= M {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BaseElement {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // always serialize the canonical representation of the element
+        serializer.serialize_u64(self.as_int())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BaseElement {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        if value >= M {
+            return Err(serde::de::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reduces a 128-bit value by M such that the output is in [0, M) range.
+///
+/// Since 2^61 = 1 mod M, the high bits of the value can be folded into the low bits by addition.
+#[inline(always)]
+fn mod_reduce(x: u128) -> u64 {
+    // x = lo + 2^61 * hi, where hi < 2^67
+    let lo = (x as u64) & M;
+    let hi = x >> M_BITS;
+
+    // fold hi into lo; the result is smaller than 2^61 + 2^61 + 2^6, and thus, fits into u64
+    let z = lo + ((hi as u64) & M) + ((hi >> M_BITS) as u64);
+
+    // fold once more; the result is at most M + 2
+    let z = (z & M) + (z >> M_BITS);
+    if z >= M {
+        z - M
+    } else {
+        z
+    }
+}
@@ -0,0 +1,494 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField};
+use crate::field::{traits::mul_with_irreducible, CubeExtension, ExtensibleField, QuadExtension};
+use core::convert::TryFrom;
+use num_bigint::BigUint;
+use proptest::prelude::*;
+use rand_utils::rand_value;
+
+// MANUAL TESTS
+// ================================================================================================
+
+#[test]
+fn add() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r + BaseElement::ZERO);
+
+    // test addition within bounds
+    assert_eq!(
+        BaseElement::from(5u8),
+        BaseElement::from(2u8) + BaseElement::from(3u8)
+    );
+
+    // test overflow
+    let t = BaseElement::from(BaseElement::MODULUS - 1);
+    assert_eq!(BaseElement::ZERO, t + BaseElement::ONE);
+    assert_eq!(BaseElement::ONE, t + BaseElement::from(2u8));
+}
+
+#[test]
+fn sub() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r - BaseElement::ZERO);
+
+    // test subtraction within bounds
+    assert_eq!(
+        BaseElement::from(2u8),
+        BaseElement::from(5u8) - BaseElement::from(3u8)
+    );
+
+    // test underflow
+    let expected = BaseElement::from(BaseElement::MODULUS - 2);
+    assert_eq!(expected, BaseElement::from(3u8) - BaseElement::from(5u8));
+}
+
+#[test]
+fn mul() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ZERO, r * BaseElement::ZERO);
+    assert_eq!(r, r * BaseElement::ONE);
+
+    // test multiplication within bounds
+    assert_eq!(
+        BaseElement::from(15u8),
+        BaseElement::from(5u8) * BaseElement::from(3u8)
+    );
+
+    // test overflow
+    let m = BaseElement::MODULUS;
+    let t = BaseElement::from(m - 1);
+    assert_eq!(BaseElement::ONE, t * t);
+    assert_eq!(BaseElement::from(m - 2), t * BaseElement::from(2u8));
+    assert_eq!(BaseElement::from(m - 4), t * BaseElement::from(4u8));
+
+    let t = (m + 1) / 2;
+    assert_eq!(
+        BaseElement::ONE,
+        BaseElement::from(t) * BaseElement::from(2u8)
+    );
+}
+
+#[test]
+fn exp() {
+    let a = BaseElement::ZERO;
+    assert_eq!(a.exp(0), BaseElement::ONE);
+    assert_eq!(a.exp(1), BaseElement::ZERO);
+
+    let a = BaseElement::ONE;
+    assert_eq!(a.exp(0), BaseElement::ONE);
+    assert_eq!(a.exp(1), BaseElement::ONE);
+    assert_eq!(a.exp(3), BaseElement::ONE);
+
+    let a: BaseElement = rand_value();
+    assert_eq!(a.exp(3), a * a * a);
+}
+
+#[test]
+fn inv() {
+    // identity
+    assert_eq!(BaseElement::ONE, BaseElement::inv(BaseElement::ONE));
+    assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
+}
+
+#[test]
+fn element_as_int() {
+    let v = u64::MAX;
+    let e = BaseElement::new(v);
+    assert_eq!(v % super::M, e.as_int());
+}
+
+#[test]
+fn equals() {
+    let a = BaseElement::ONE;
+    let b = BaseElement::new(super::M - 1) * BaseElement::new(super::M - 1);
+
+    // elements are equal, and since the field is canonical, so is their internal representation
+    assert_eq!(a, b);
+    assert_eq!(a.as_int(), b.as_int());
+    assert_eq!(a.to_bytes(), b.to_bytes());
+    assert_eq!(a.as_bytes(), b.as_bytes());
+}
+
+// QUADRATIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn quad_mul() {
+    // identity
+    let r: QuadExtension<BaseElement> = rand_value();
+    assert_eq!(
+        <QuadExtension<BaseElement>>::ZERO,
+        r * <QuadExtension<BaseElement>>::ZERO
+    );
+    assert_eq!(r, r * <QuadExtension<BaseElement>>::ONE);
+
+    // φ^2 = -1
+    let phi = <QuadExtension<BaseElement>>::new(BaseElement::ZERO, BaseElement::ONE);
+    assert_eq!(-<QuadExtension<BaseElement>>::ONE, phi * phi);
+
+    // test multiplication with overflow
+    let m = BaseElement::MODULUS;
+    let a = <QuadExtension<BaseElement>>::new(BaseElement::new(m - 5), BaseElement::new(17));
+    let b = <QuadExtension<BaseElement>>::new(BaseElement::new(3), BaseElement::new(m - 4));
+    let expected = <QuadExtension<BaseElement>>::new(BaseElement::new(53), BaseElement::new(71));
+    assert_eq!(expected, a * b);
+}
+
+#[test]
+fn quad_conjugate() {
+    let r: QuadExtension<BaseElement> = rand_value();
+    let p = BaseElement::MODULUS;
+    assert_eq!(r.exp(p), r.conjugate());
+}
+
+#[test]
+fn irreducible_mul() {
+    // hand-written multiplication must match the default one derived from the polynomial
+    for _ in 0..100 {
+        let a = [rand_value(), rand_value()];
+        let b = [rand_value(), rand_value()];
+        let coeffs = <BaseElement as ExtensibleField<2>>::IRREDUCIBLE_COEFFS.unwrap();
+        assert_eq!(
+            mul_with_irreducible(a, b, &coeffs),
+            <BaseElement as ExtensibleField<2>>::mul(a, b)
+        );
+
+        let a = [rand_value(), rand_value(), rand_value()];
+        let b = [rand_value(), rand_value(), rand_value()];
+        let coeffs = <BaseElement as ExtensibleField<3>>::IRREDUCIBLE_COEFFS.unwrap();
+        assert_eq!(
+            mul_with_irreducible(a, b, &coeffs),
+            <BaseElement as ExtensibleField<3>>::mul(a, b)
+        );
+    }
+}
+
+// CUBIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn cube_mul() {
+    // identity
+    let r: CubeExtension<BaseElement> = rand_value();
+    assert_eq!(
+        <CubeExtension<BaseElement>>::ZERO,
+        r * <CubeExtension<BaseElement>>::ZERO
+    );
+    assert_eq!(r, r * <CubeExtension<BaseElement>>::ONE);
+
+    // test multiplication within bounds
+    let a = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(15),
+        BaseElement::new(22),
+        BaseElement::new(8),
+    );
+    let b = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(20),
+        BaseElement::new(22),
+        BaseElement::new(6),
+    );
+    let expected = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(1840),
+        BaseElement::new(1010),
+        BaseElement::new(734),
+    );
+    assert_eq!(expected, a * b);
+
+    // test multiplication with overflow
+    let m = BaseElement::MODULUS;
+    let a = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(m - 100),
+        BaseElement::new(m - 2000),
+        BaseElement::new(m - 33333),
+    );
+    let b = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(m - 1),
+        BaseElement::new(999999999),
+        BaseElement::new(m - 123456789),
+    );
+    let expected = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(2305677578781750716),
+        BaseElement::new(20475925740785),
+        BaseElement::new(2305841021559408184),
+    );
+    assert_eq!(expected, a * b);
+}
+
+#[test]
+fn cube_inv() {
+    // identity
+    assert_eq!(
+        <CubeExtension<BaseElement>>::ONE,
+        <CubeExtension<BaseElement>>::ONE.inv()
+    );
+    assert_eq!(
+        <CubeExtension<BaseElement>>::ZERO,
+        <CubeExtension<BaseElement>>::ZERO.inv()
+    );
+
+    let r: CubeExtension<BaseElement> = rand_value();
+    assert_eq!(<CubeExtension<BaseElement>>::ONE, r * r.inv());
+}
+
+#[test]
+fn cube_frobenius() {
+    let r: CubeExtension<BaseElement> = rand_value();
+    let p = BaseElement::MODULUS;
+    assert_eq!(r.exp(p), r.frobenius());
+    assert_eq!(r, r.frobenius().frobenius().frobenius());
+}
+
+#[test]
+fn cube_sqrt() {
+    for _ in 0..10 {
+        let r: CubeExtension<BaseElement> = rand_value();
+        let square = r.square();
+        assert_eq!(square, square.sqrt().unwrap().square());
+    }
+
+    let g = CubeExtension::<BaseElement>::from(BaseElement::GENERATOR);
+    assert_eq!(None, g.sqrt());
+}
+
+#[test]
+fn cube_frobenius_squared() {
+    // precomputed squared automorphism must match applying frobenius automorphism twice
+    for _ in 0..100 {
+        let x = rand_value::<CubeExtension<BaseElement>>().to_array();
+        let expected = <BaseElement as ExtensibleField<3>>::frobenius(
+            <BaseElement as ExtensibleField<3>>::frobenius(x),
+        );
+        assert_eq!(
+            expected,
+            <BaseElement as ExtensibleField<3>>::frobenius_squared(x)
+        );
+    }
+}
+
+// ROOTS OF UNITY
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn get_root_of_unity() {
+    let root_1 = BaseElement::get_root_of_unity(1);
+    assert_eq!(BaseElement::TWO_ADIC_ROOT_OF_UNITY, root_1);
+    assert_eq!(-BaseElement::ONE, root_1);
+    assert_eq!(BaseElement::ONE, root_1.exp(2));
+}
+
+#[test]
+fn generator() {
+    // the generator must not lie in any proper subgroup of the multiplicative group; the
+    // factorization of M - 1 was computed using SageMath
+    let factors = [2u64, 3, 5, 7, 11, 13, 31, 41, 61, 151, 331, 1321];
+    for f in factors {
+        let e = (BaseElement::MODULUS - 1) / f;
+        assert_ne!(BaseElement::ONE, BaseElement::GENERATOR.exp(e));
+    }
+}
+
+// SERIALIZATION AND DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn from_u128() {
+    let v = u128::MAX;
+    let e = BaseElement::from(v);
+    assert_eq!((v % super::M as u128) as u64, e.as_int());
+}
+
+#[test]
+fn try_from_slice() {
+    let bytes = vec![1, 0, 0, 0, 0, 0, 0, 0];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_ok());
+    assert_eq!(1, result.unwrap().as_int());
+
+    let bytes = vec![1, 0, 0, 0, 0, 0, 0];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+
+    let bytes = vec![1, 0, 0, 0, 0, 0, 0, 0, 0];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+
+    let bytes = vec![255, 255, 255, 255, 255, 255, 255, 31];
+    let result = BaseElement::try_from(bytes.as_slice());
+    assert!(result.is_err());
+}
+
+#[test]
+fn elements_as_bytes() {
+    let source = vec![
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ];
+
+    let expected: Vec<u8> = vec![
+        1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+        0, 0,
+    ];
+
+    assert_eq!(expected, BaseElement::elements_as_bytes(&source));
+}
+
+#[test]
+fn bytes_as_elements() {
+    let bytes: Vec<u8> = vec![
+        1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0,
+        0, 0, 5,
+    ];
+
+    let expected = vec![
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ];
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..32]) };
+    assert!(result.is_ok());
+    assert_eq!(expected, result.unwrap());
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..33]) };
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[1..33]) };
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+// INITIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn zeroed_vector() {
+    let result = BaseElement::zeroed_vector(4);
+    assert_eq!(4, result.len());
+    for element in result.into_iter() {
+        assert_eq!(BaseElement::ZERO, element);
+    }
+}
+
+#[test]
+fn extension_degree() {
+    const _: () = assert!(BaseElement::EXTENSION_DEGREE == 1);
+    const _: () = assert!(QuadExtension::<BaseElement>::EXTENSION_DEGREE == 2);
+    const _: () =
+        assert!(QuadExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 2);
+    const _: () = assert!(CubeExtension::<BaseElement>::EXTENSION_DEGREE == 3);
+    const _: () =
+        assert!(CubeExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 3);
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+
+    #[test]
+    fn add_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let v1 = BaseElement::from(a);
+        let v2 = BaseElement::from(b);
+        let result = v1 + v2;
+
+        let expected = (a % super::M + b % super::M) % super::M;
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn sub_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let v1 = BaseElement::from(a);
+        let v2 = BaseElement::from(b);
+        let result = v1 - v2;
+
+        let a = a % super::M;
+        let b = b % super::M;
+        let expected = if a < b { super::M - b + a } else { a - b };
+
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn mul_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let v1 = BaseElement::from(a);
+        let v2 = BaseElement::from(b);
+        let result = v1 * v2;
+
+        let expected = (((a % super::M) as u128 * (b % super::M) as u128) % super::M as u128) as u64;
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn exp_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let result = BaseElement::from(a).exp(b);
+
+        let b = BigUint::from(b);
+        let m = BigUint::from(super::M);
+        let expected = BigUint::from(a).modpow(&b, &m).to_u64_digits().first().copied().unwrap_or(0);
+        prop_assert_eq!(expected, result.as_int());
+    }
+
+    #[test]
+    fn inv_proptest(a in any::<u64>()) {
+        let a = BaseElement::from(a);
+        let b = a.inv();
+
+        let expected = if a == BaseElement::ZERO { BaseElement::ZERO } else { BaseElement::ONE };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    #[test]
+    fn element_as_int_proptest(a in any::<u64>()) {
+        let e = BaseElement::new(a);
+        prop_assert_eq!(a % super::M, e.as_int());
+    }
+
+    #[test]
+    fn from_u128_proptest(v in any::<u128>()) {
+        let e = BaseElement::from(v);
+        assert_eq!((v % super::M as u128) as u64, e.as_int());
+    }
+
+    // QUADRATIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn quad_mul_inv_proptest(a0 in any::<u64>(), a1 in any::<u64>()) {
+        let a = QuadExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1));
+        let b = a.inv();
+
+        let expected = if a == QuadExtension::<BaseElement>::ZERO {
+            QuadExtension::<BaseElement>::ZERO
+        } else {
+            QuadExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
+
+    // CUBIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn cube_square_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        let a = CubeExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2));
+        prop_assert_eq!(a * a, a.square());
+    }
+
+    #[test]
+    fn cube_mul_inv_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        let a = CubeExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2));
+        let b = a.inv();
+
+        let expected = if a == CubeExtension::<BaseElement>::ZERO {
+            CubeExtension::<BaseElement>::ZERO
+        } else {
+            CubeExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
+}
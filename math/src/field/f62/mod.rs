@@ -10,7 +10,7 @@
 //! stored in the Montgomery form using `u64` as the backing type.
 
 use super::{
-    traits::{FieldElement, StarkField},
+    traits::{FieldElement, MontgomeryField, StarkField},
     ExtensibleField,
 };
 use core::{
@@ -154,6 +154,10 @@ impl FieldElement for BaseElement {
     fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
         elements
     }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        elements
+    }
 }
 
 impl StarkField for BaseElement {
@@ -189,6 +193,48 @@ impl StarkField for BaseElement {
         // since the result of multiplication can be in [0, 2M), we need to normalize it
         normalize(result)
     }
+
+    #[inline]
+    fn normalize(self) -> Self {
+        // internal values can be in [0, 2M) range; reducing them to [0, M) makes the Montgomery
+        // representation unique
+        Self(ct_normalize(self.0))
+    }
+
+    #[inline]
+    fn select(a: Self, b: Self, choice: bool) -> Self {
+        // mask is all ones when choice is true and all zeros otherwise
+        let mask = 0u64.wrapping_sub(choice as u64);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        // internal values can be in [0, 2M) range, so we normalize them first; diff is ZERO only
+        // if the normalized values are the same
+        let diff = ct_normalize(self.0) ^ ct_normalize(other.0);
+        (diff | diff.wrapping_neg()) >> 63 == 0
+    }
+}
+
+impl MontgomeryField for BaseElement {
+    fn write_montgomery_into<W: ByteWriter>(&self, target: &mut W) {
+        // internal values can be in [0, 2M) range; they are reduced into [0, M) range so that
+        // the encoding is unique
+        target.write_u8_slice(&normalize(self.0).to_le_bytes());
+    }
+
+    fn read_montgomery_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value = source.read_u64()?;
+        if value >= M {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid field element: Montgomery value {} is greater than or equal to the field \
+                modulus",
+                value
+            )));
+        }
+        Ok(Self(value))
+    }
 }
 
 impl Randomizable for BaseElement {
@@ -293,6 +339,8 @@ impl Neg for BaseElement {
 /// x - 1. Thus, an extension element is defined as α + β * φ, where φ is a root of this polynomial,
 /// and α and β are base field elements.
 impl ExtensibleField<2> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 2]> = Some([BaseElement::new(1), BaseElement::new(1)]);
+
     #[inline(always)]
     fn mul(a: [Self; 2], b: [Self; 2]) -> [Self; 2] {
         let z = a[0] * b[0];
@@ -312,6 +360,19 @@ impl ExtensibleField<2> for BaseElement {
 /// 2x + 2. Thus, an extension element is defined as α + β * φ + γ * φ^2, where φ is a root of this
 /// polynomial, and α, β and γ are base field elements.
 impl ExtensibleField<3> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(M - 2),
+        BaseElement::new(M - 2),
+        BaseElement::new(0),
+    ]);
+
+    /// 2 + φ is a generator of the multiplicative group of order p^3 - 1
+    const GENERATOR_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(2),
+        BaseElement::new(1),
+        BaseElement::new(0),
+    ]);
+
     #[inline(always)]
     fn mul(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
         // performs multiplication in the extension field using 6 multiplications, 8 additions,
@@ -339,6 +400,25 @@ impl ExtensibleField<3> for BaseElement {
         ]
     }
 
+    #[inline(always)]
+    fn square(a: [Self; 3]) -> [Self; 3] {
+        // performs squaring in the extension field using 5 multiplications in the base field
+        // (Chung-Hasan SQR2 method). before reduction, the coefficients of the square are:
+        // [s0, s1, s1 + s2 + s3 - s0 - s4, s3, s4]; since φ^3 = -2φ - 2 and φ^4 = -2φ^2 - 2φ,
+        // the reduced coefficients are computed as shown below.
+        let s0 = a[0].square();
+        let s1 = (a[0] * a[1]).double();
+        let s2 = (a[0] - a[1] + a[2]).square();
+        let s3 = (a[1] * a[2]).double();
+        let s4 = a[2].square();
+
+        [
+            s0 - s3.double(),
+            s1 - (s3 + s4).double(),
+            s1 + s2 + s3 - s0 - s4.double() - s4,
+        ]
+    }
+
     #[inline(always)]
     fn frobenius(x: [Self; 3]) -> [Self; 3] {
         // coefficients were computed using SageMath
@@ -351,6 +431,26 @@ impl ExtensibleField<3> for BaseElement {
                 + BaseElement::new(1743033688129053336) * x[2],
         ]
     }
+
+    #[inline(always)]
+    fn frobenius_squared(x: [Self; 3]) -> [Self; 3] {
+        // coefficients are the square of the matrix of the frobenius automorphism
+        [
+            x[0] + BaseElement::new(2549858939913771556) * x[1]
+                + BaseElement::new(3824788409870657332) * x[2],
+            BaseElement::new(1743033688129053336) * x[1]
+                + BaseElement::new(1274929469956885778) * x[2],
+            BaseElement::new(1912394204935328667) * x[1]
+                + BaseElement::new(2868591307402993000) * x[2],
+        ]
+    }
+
+    fn sqrt_params() -> Option<(u32, &'static [u64])> {
+        // the order of the multiplicative group is p^3 - 1 = (p - 1) * (p^2 + p + 1); since
+        // p^2 + p + 1 is odd, two-adicity of the group is the same as for the base field.
+        // the cofactor was computed using SageMath
+        Some((39, &[9243692661028683443, 3463844647456751449, 524267]))
+    }
 }
 
 // TYPE CONVERSIONS
@@ -484,6 +584,28 @@ impl Deserializable for BaseElement {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for BaseElement {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // always serialize the canonical representation of the element
+        serializer.serialize_u64(self.as_int())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BaseElement {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        if value >= M {
+            return Err(serde::de::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(BaseElement::new(value))
+    }
+}
+
 // FINITE FIELD ARITHMETIC
 // ================================================================================================
 
@@ -579,3 +701,11 @@ fn normalize(value: u64) -> u64 {
         value
     }
 }
+
+/// Reduces any value in [0, 2M) range to [0, M) range without branching on the value.
+#[inline(always)]
+fn ct_normalize(value: u64) -> u64 {
+    // mask is all ones when value >= M and all zeros otherwise
+    let mask = 0u64.wrapping_sub((value >= M) as u64);
+    value.wrapping_sub(M & mask)
+}
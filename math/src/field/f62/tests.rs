@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField};
-use crate::field::{CubeExtension, QuadExtension};
+use crate::field::{traits::mul_with_irreducible, CubeExtension, ExtensibleField, QuadExtension};
 use core::convert::TryFrom;
 use num_bigint::BigUint;
 use proptest::prelude::*;
@@ -119,6 +119,28 @@ fn equals() {
     assert_ne!(a.as_bytes(), b.as_bytes());
 }
 
+#[test]
+fn irreducible_mul() {
+    // hand-written multiplication must match the default one derived from the polynomial
+    for _ in 0..100 {
+        let a = [rand_value(), rand_value()];
+        let b = [rand_value(), rand_value()];
+        let coeffs = <BaseElement as ExtensibleField<2>>::IRREDUCIBLE_COEFFS.unwrap();
+        assert_eq!(
+            mul_with_irreducible(a, b, &coeffs),
+            <BaseElement as ExtensibleField<2>>::mul(a, b)
+        );
+
+        let a = [rand_value(), rand_value(), rand_value()];
+        let b = [rand_value(), rand_value(), rand_value()];
+        let coeffs = <BaseElement as ExtensibleField<3>>::IRREDUCIBLE_COEFFS.unwrap();
+        assert_eq!(
+            mul_with_irreducible(a, b, &coeffs),
+            <BaseElement as ExtensibleField<3>>::mul(a, b)
+        );
+    }
+}
+
 // CUBIC EXTENSION
 // ------------------------------------------------------------------------------------------------
 #[test]
@@ -185,6 +207,33 @@ fn cube_mul() {
     assert_eq!(expected, a * b);
 }
 
+#[test]
+fn cube_sqrt() {
+    for _ in 0..10 {
+        let r: CubeExtension<BaseElement> = rand_value();
+        let square = r.square();
+        assert_eq!(square, square.sqrt().unwrap().square());
+    }
+
+    let g = CubeExtension::<BaseElement>::from(BaseElement::GENERATOR);
+    assert_eq!(None, g.sqrt());
+}
+
+#[test]
+fn cube_frobenius_squared() {
+    // precomputed squared automorphism must match applying frobenius automorphism twice
+    for _ in 0..100 {
+        let x = rand_value::<CubeExtension<BaseElement>>().to_array();
+        let expected = <BaseElement as ExtensibleField<3>>::frobenius(
+            <BaseElement as ExtensibleField<3>>::frobenius(x),
+        );
+        assert_eq!(
+            expected,
+            <BaseElement as ExtensibleField<3>>::frobenius_squared(x)
+        );
+    }
+}
+
 // ROOTS OF UNITY
 // ------------------------------------------------------------------------------------------------
 
@@ -287,6 +336,17 @@ fn zeroed_vector() {
     }
 }
 
+#[test]
+fn extension_degree() {
+    const _: () = assert!(BaseElement::EXTENSION_DEGREE == 1);
+    const _: () = assert!(QuadExtension::<BaseElement>::EXTENSION_DEGREE == 2);
+    const _: () =
+        assert!(QuadExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 2);
+    const _: () = assert!(CubeExtension::<BaseElement>::EXTENSION_DEGREE == 3);
+    const _: () =
+        assert!(CubeExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 3);
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -356,6 +416,13 @@ proptest! {
         assert_eq!((v % super::M as u128) as u64, e.as_int());
     }
 
+    #[test]
+    fn reduce_le_bytes_proptest(bytes in prop::collection::vec(any::<u8>(), 0..100)) {
+        let expected = BigUint::from_bytes_le(&bytes) % BigUint::from(super::M);
+        let e = BaseElement::reduce_le_bytes(&bytes);
+        prop_assert_eq!(expected, BigUint::from(e.as_int()));
+    }
+
     // QUADRATIC EXTENSION
     // --------------------------------------------------------------------------------------------
     #[test]
@@ -373,6 +440,12 @@ proptest! {
 
     // CUBIC EXTENSION
     // --------------------------------------------------------------------------------------------
+    #[test]
+    fn cube_square_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        let a = CubeExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2));
+        prop_assert_eq!(a * a, a.square());
+    }
+
     #[test]
     fn cube_mul_inv_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
         let a = CubeExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2));
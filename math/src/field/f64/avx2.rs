@@ -0,0 +1,195 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AVX2 implementations of pointwise addition and subtraction of slices of field elements, and of
+//! multiplication of a slice of field elements by a scalar.
+//!
+//! The results are bit-identical to the results of the scalar [Add](core::ops::Add),
+//! [Sub](core::ops::Sub), and [Mul](core::ops::Mul) implementations for [BaseElement]; that is,
+//! even the non-canonical internal representations of the results are the same.
+
+use super::{BaseElement, E, M};
+use core::arch::x86_64::*;
+
+/// Number of field elements processed by a single AVX2 instruction.
+const LANES: usize = 4;
+
+/// Returns true if AVX2 instructions can be used on the current CPU.
+///
+/// When `std` feature is enabled, this is detected at runtime; otherwise, AVX2 is used only if it
+/// was enabled at compile time (e.g., via `-C target-feature=+avx2`).
+#[inline(always)]
+pub fn is_available() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+/// Writes `a[i] + b[i]` into `result[i]` for all i.
+///
+/// # Safety
+/// The caller must make sure that AVX2 instructions are available (see [is_available()]), and
+/// that all slices have the same length.
+#[target_feature(enable = "avx2")]
+pub unsafe fn add(a: &[BaseElement], b: &[BaseElement], result: &mut [BaseElement]) {
+    debug_assert!(a.len() == b.len() && a.len() == result.len());
+    let n = a.len() - a.len() % LANES;
+    let (sign, m, m_flipped) = constants();
+
+    for i in (0..n).step_by(LANES) {
+        let x = _mm256_loadu_si256(a.as_ptr().add(i) as *const __m256i);
+        let y = canonicalize(
+            _mm256_loadu_si256(b.as_ptr().add(i) as *const __m256i),
+            sign,
+            m,
+            m_flipped,
+        );
+
+        // the sum overflows iff x > x + y when interpreted as unsigned integers; in this case,
+        // M is subtracted from the sum (with wrapping) to account for the overflowed 2^64
+        let sum = _mm256_add_epi64(x, y);
+        let over = _mm256_cmpgt_epi64(_mm256_xor_si256(x, sign), _mm256_xor_si256(sum, sign));
+        let r = _mm256_sub_epi64(sum, _mm256_and_si256(over, m));
+        _mm256_storeu_si256(result.as_mut_ptr().add(i) as *mut __m256i, r);
+    }
+
+    for ((r, &x), &y) in result[n..].iter_mut().zip(&a[n..]).zip(&b[n..]) {
+        *r = x + y;
+    }
+}
+
+/// Writes `a[i] - b[i]` into `result[i]` for all i.
+///
+/// # Safety
+/// The caller must make sure that AVX2 instructions are available (see [is_available()]), and
+/// that all slices have the same length.
+#[target_feature(enable = "avx2")]
+pub unsafe fn sub(a: &[BaseElement], b: &[BaseElement], result: &mut [BaseElement]) {
+    debug_assert!(a.len() == b.len() && a.len() == result.len());
+    let n = a.len() - a.len() % LANES;
+    let (sign, m, m_flipped) = constants();
+
+    for i in (0..n).step_by(LANES) {
+        let x = _mm256_loadu_si256(a.as_ptr().add(i) as *const __m256i);
+        let y = canonicalize(
+            _mm256_loadu_si256(b.as_ptr().add(i) as *const __m256i),
+            sign,
+            m,
+            m_flipped,
+        );
+
+        // the difference underflows iff y > x when interpreted as unsigned integers; in this
+        // case, M is added to the difference (with wrapping) to account for the borrowed 2^64
+        let diff = _mm256_sub_epi64(x, y);
+        let under = _mm256_cmpgt_epi64(_mm256_xor_si256(y, sign), _mm256_xor_si256(x, sign));
+        let r = _mm256_add_epi64(diff, _mm256_and_si256(under, m));
+        _mm256_storeu_si256(result.as_mut_ptr().add(i) as *mut __m256i, r);
+    }
+
+    for ((r, &x), &y) in result[n..].iter_mut().zip(&a[n..]).zip(&b[n..]) {
+        *r = x - y;
+    }
+}
+
+/// Multiplies all elements of `values` by `scalar` in place.
+///
+/// # Safety
+/// The caller must make sure that AVX2 instructions are available (see [is_available()]).
+#[target_feature(enable = "avx2")]
+pub unsafe fn mul_scalar(values: &mut [BaseElement], scalar: BaseElement) {
+    let n = values.len() - values.len() % LANES;
+    let (sign, _, _) = constants();
+    let eps = _mm256_set1_epi64x(E as i64);
+    let lo_mask = _mm256_set1_epi64x(u32::MAX as i64);
+
+    // the scalar is used as is (i.e., without canonicalization), same as in the scalar version
+    let y = _mm256_set1_epi64x(scalar.0 as i64);
+    let y_hi = _mm256_srli_epi64(y, 32);
+
+    for i in (0..n).step_by(LANES) {
+        let ptr = values.as_mut_ptr().add(i) as *mut __m256i;
+        let x = _mm256_loadu_si256(ptr);
+        let x_hi = _mm256_srli_epi64(x, 32);
+
+        // compute the 128-bit product x * y = hi * 2^64 + lo from four 32-bit products; none of
+        // the intermediate sums below can overflow 64 bits
+        let ll = _mm256_mul_epu32(x, y);
+        let lh = _mm256_mul_epu32(x, y_hi);
+        let hl = _mm256_mul_epu32(x_hi, y);
+        let hh = _mm256_mul_epu32(x_hi, y_hi);
+
+        let t = _mm256_add_epi64(hl, _mm256_srli_epi64(ll, 32));
+        let u = _mm256_add_epi64(lh, _mm256_and_si256(t, lo_mask));
+        let lo = _mm256_or_si256(_mm256_slli_epi64(u, 32), _mm256_and_si256(ll, lo_mask));
+        let hi = _mm256_add_epi64(
+            hh,
+            _mm256_add_epi64(_mm256_srli_epi64(t, 32), _mm256_srli_epi64(u, 32)),
+        );
+
+        _mm256_storeu_si256(ptr, mod_reduce(lo, hi, sign, eps, lo_mask));
+    }
+
+    for value in values[n..].iter_mut() {
+        *value *= scalar;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the sign bit mask, the modulus, and the modulus with the sign bit flipped, each
+/// broadcast into all lanes.
+///
+/// AVX2 supports only signed comparisons of 64-bit integers; flipping the sign bit of both
+/// operands turns these into unsigned comparisons.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn constants() -> (__m256i, __m256i, __m256i) {
+    let sign = _mm256_set1_epi64x(i64::MIN);
+    let m = _mm256_set1_epi64x(M as i64);
+    (sign, m, _mm256_xor_si256(m, sign))
+}
+
+/// Reduces the values in all lanes into the canonical range [0, M); this is the same as
+/// [BaseElement::as_int()](crate::StarkField::as_int) for each lane.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn canonicalize(y: __m256i, sign: __m256i, m: __m256i, m_flipped: __m256i) -> __m256i {
+    // subtract M from lanes where y >= M; i.e., where M > y does not hold
+    let lt = _mm256_cmpgt_epi64(m_flipped, _mm256_xor_si256(y, sign));
+    _mm256_sub_epi64(y, _mm256_andnot_si256(lt, m))
+}
+
+/// Reduces 128-bit values hi * 2^64 + lo in all lanes by M such that the outputs are in [0, 2^64)
+/// range; this follows the steps of the scalar `mod_reduce()` exactly.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mod_reduce(
+    lo: __m256i,
+    hi: __m256i,
+    sign: __m256i,
+    eps: __m256i,
+    lo_mask: __m256i,
+) -> __m256i {
+    let c = _mm256_and_si256(hi, lo_mask);
+    let d = _mm256_srli_epi64(hi, 32);
+
+    // compute lo - d, and subtract E from lanes where this underflowed
+    let under = _mm256_cmpgt_epi64(_mm256_xor_si256(d, sign), _mm256_xor_si256(lo, sign));
+    let tmp0 = _mm256_sub_epi64(_mm256_sub_epi64(lo, d), _mm256_and_si256(under, eps));
+
+    // compute c * 2^32 - c; this cannot underflow
+    let tmp1 = _mm256_sub_epi64(_mm256_slli_epi64(c, 32), c);
+
+    // add the temp values, and add E to lanes where this overflowed
+    let sum = _mm256_add_epi64(tmp0, tmp1);
+    let over = _mm256_cmpgt_epi64(_mm256_xor_si256(tmp0, sign), _mm256_xor_si256(sum, sign));
+    _mm256_add_epi64(sum, _mm256_and_si256(over, eps))
+}
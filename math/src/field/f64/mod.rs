@@ -30,6 +30,9 @@ use utils::{
     DeserializationError, Randomizable, Serializable,
 };
 
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+
 #[cfg(test)]
 mod tests;
 
@@ -178,6 +181,10 @@ impl FieldElement for BaseElement {
     fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
         elements
     }
+
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        elements
+    }
 }
 
 impl StarkField for BaseElement {
@@ -216,6 +223,28 @@ impl StarkField for BaseElement {
             self.0
         }
     }
+
+    #[inline]
+    fn normalize(self) -> Self {
+        // internal values can be in [0, 2^64) range; reducing them to the canonical form makes
+        // the representation unique
+        Self(ct_normalize(self.0))
+    }
+
+    #[inline]
+    fn select(a: Self, b: Self, choice: bool) -> Self {
+        // mask is all ones when choice is true and all zeros otherwise
+        let mask = 0u64.wrapping_sub(choice as u64);
+        Self(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        // internal values can be in [0, 2^64) range, so we reduce them to the canonical form
+        // first; diff is ZERO only if the canonical values are the same
+        let diff = ct_normalize(self.0) ^ ct_normalize(other.0);
+        (diff | diff.wrapping_neg()) >> 63 == 0
+    }
 }
 
 impl Randomizable for BaseElement {
@@ -340,6 +369,9 @@ impl Neg for BaseElement {
 /// x + 2. Thus, an extension element is defined as α + β * φ, where φ is a root of this polynomial,
 /// and α and β are base field elements.
 impl ExtensibleField<2> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 2]> =
+        Some([BaseElement::new(M - 2), BaseElement::new(1)]);
+
     #[inline(always)]
     fn mul(a: [Self; 2], b: [Self; 2]) -> [Self; 2] {
         // performs multiplication in the extension field using 3 multiplications, 3 additions,
@@ -365,6 +397,19 @@ impl ExtensibleField<2> for BaseElement {
 /// x - 1. Thus, an extension element is defined as α + β * φ + γ * φ^2, where φ is a root of this
 /// polynomial, and α, β and γ are base field elements.
 impl ExtensibleField<3> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(1),
+        BaseElement::new(1),
+        BaseElement::new(0),
+    ]);
+
+    /// 2 + φ is a generator of the multiplicative group of order p^3 - 1
+    const GENERATOR_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(2),
+        BaseElement::new(1),
+        BaseElement::new(0),
+    ]);
+
     #[inline(always)]
     fn mul(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
         // performs multiplication in the extension field using 6 multiplications, 9 additions,
@@ -392,6 +437,21 @@ impl ExtensibleField<3> for BaseElement {
         ]
     }
 
+    #[inline(always)]
+    fn square(a: [Self; 3]) -> [Self; 3] {
+        // performs squaring in the extension field using 5 multiplications in the base field
+        // (Chung-Hasan SQR2 method). before reduction, the coefficients of the square are:
+        // [s0, s1, s1 + s2 + s3 - s0 - s4, s3, s4]; since φ^3 = φ + 1 and φ^4 = φ^2 + φ, the
+        // reduced coefficients are computed as shown below.
+        let s0 = a[0].square();
+        let s1 = (a[0] * a[1]).double();
+        let s2 = (a[0] - a[1] + a[2]).square();
+        let s3 = (a[1] * a[2]).double();
+        let s4 = a[2].square();
+
+        [s0 + s3, s1 + s3 + s4, s1 + s2 + s3 - s0]
+    }
+
     #[inline(always)]
     fn frobenius(x: [Self; 3]) -> [Self; 3] {
         // coefficients were computed using SageMath
@@ -404,6 +464,175 @@ impl ExtensibleField<3> for BaseElement {
                 + BaseElement::new(8396469466686423992) * x[2],
         ]
     }
+
+    #[inline(always)]
+    fn frobenius_squared(x: [Self; 3]) -> [Self; 3] {
+        // coefficients are the square of the matrix of the frobenius automorphism
+        [
+            x[0] + BaseElement::new(7831040667286096068) * x[1]
+                + BaseElement::new(11746561000929144103) * x[2],
+            BaseElement::new(8396469466686423992) * x[1]
+                + BaseElement::new(3915520333643048034) * x[2],
+            BaseElement::new(6700183068485440219) * x[1]
+                + BaseElement::new(10050274602728160328) * x[2],
+        ]
+    }
+
+    #[inline(always)]
+    fn add_coeffs(a: &[Self], b: &[Self], result: &mut [Self]) {
+        #[cfg(target_arch = "x86_64")]
+        if avx2::is_available() && a.len() == b.len() && a.len() == result.len() {
+            // SAFETY: AVX2 support was checked above, and all slices have the same length
+            return unsafe { avx2::add(a, b, result) };
+        }
+        for ((r, &a), &b) in result.iter_mut().zip(a).zip(b) {
+            *r = a + b;
+        }
+    }
+
+    #[inline(always)]
+    fn sub_coeffs(a: &[Self], b: &[Self], result: &mut [Self]) {
+        #[cfg(target_arch = "x86_64")]
+        if avx2::is_available() && a.len() == b.len() && a.len() == result.len() {
+            // SAFETY: AVX2 support was checked above, and all slices have the same length
+            return unsafe { avx2::sub(a, b, result) };
+        }
+        for ((r, &a), &b) in result.iter_mut().zip(a).zip(b) {
+            *r = a - b;
+        }
+    }
+
+    #[inline(always)]
+    fn scale_coeffs(values: &mut [Self], scalar: Self) {
+        #[cfg(target_arch = "x86_64")]
+        if avx2::is_available() {
+            // SAFETY: AVX2 support was checked above
+            return unsafe { avx2::mul_scalar(values, scalar) };
+        }
+        for value in values.iter_mut() {
+            *value *= scalar;
+        }
+    }
+
+    fn sqrt_params() -> Option<(u32, &'static [u64])> {
+        // the order of the multiplicative group is p^3 - 1 = (p - 1) * (p^2 + p + 1); since
+        // p^2 + p + 1 is odd, two-adicity of the group is the same as for the base field.
+        // the cofactor was computed using SageMath
+        Some((32, &[25769803773, 25769803769, 4294967293]))
+    }
+}
+
+// QUARTIC EXTENSION
+// ================================================================================================
+
+/// Defines a quartic extension of the base field over an irreducible polynomial x<sup>4</sup> -
+/// 7. Thus, an extension element is defined as α + β * φ + γ * φ^2 + δ * φ^3, where φ is a root of
+/// this polynomial, and α, β, γ and δ are base field elements.
+impl ExtensibleField<4> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 4]> = Some([
+        BaseElement::new(7),
+        BaseElement::new(0),
+        BaseElement::new(0),
+        BaseElement::new(0),
+    ]);
+
+    #[inline(always)]
+    fn mul(a: [Self; 4], b: [Self; 4]) -> [Self; 4] {
+        // performs schoolbook multiplication in the extension field using 16 multiplications in
+        // the base field; since φ^4 = 7, all terms of degree 4 and higher are folded back into
+        // lower degree terms by multiplying them by 7.
+        let w = BaseElement::new(7);
+        [
+            a[0] * b[0] + w * (a[1] * b[3] + a[2] * b[2] + a[3] * b[1]),
+            a[0] * b[1] + a[1] * b[0] + w * (a[2] * b[3] + a[3] * b[2]),
+            a[0] * b[2] + a[1] * b[1] + a[2] * b[0] + w * (a[3] * b[3]),
+            a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0],
+        ]
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 4]) -> [Self; 4] {
+        // φ^p = φ * 7^((p - 1) / 4) = φ * 2^48; thus, the i-th coordinate is multiplied by
+        // 2^(48 * i), and 2^96 = -1
+        [
+            x[0],
+            BaseElement::new(281474976710656) * x[1],
+            -x[2],
+            BaseElement::new(18446462594437873665) * x[3],
+        ]
+    }
+}
+
+// SEXTIC EXTENSION
+// ================================================================================================
+
+/// Defines a sextic extension of the base field over an irreducible polynomial x<sup>6</sup> - 7.
+/// Thus, an extension element is defined as a_0 + a_1 * φ + ... + a_5 * φ^5, where φ is a root of
+/// this polynomial, and a_0, ..., a_5 are base field elements.
+///
+/// The polynomial is irreducible because 7 is a generator of the multiplicative group of the base
+/// field, and thus, it is neither a square nor a cube.
+impl ExtensibleField<6> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 6]> = Some([
+        BaseElement::new(7),
+        BaseElement::new(0),
+        BaseElement::new(0),
+        BaseElement::new(0),
+        BaseElement::new(0),
+        BaseElement::new(0),
+    ]);
+
+    #[inline(always)]
+    fn mul(a: [Self; 6], b: [Self; 6]) -> [Self; 6] {
+        // split each element into low and high halves such that a = a_lo + a_hi * φ^3, and
+        // multiply the halves as polynomials using one level of Karatsuba; this requires 18
+        // multiplications in the base field (as compared to 36 for schoolbook multiplication).
+        let a_lo = [a[0], a[1], a[2]];
+        let a_hi = [a[3], a[4], a[5]];
+        let b_lo = [b[0], b[1], b[2]];
+        let b_hi = [b[3], b[4], b[5]];
+
+        let lo = mul_deg2_polys(a_lo, b_lo);
+        let hi = mul_deg2_polys(a_hi, b_hi);
+        let mid = mul_deg2_polys(
+            [a_lo[0] + a_hi[0], a_lo[1] + a_hi[1], a_lo[2] + a_hi[2]],
+            [b_lo[0] + b_hi[0], b_lo[1] + b_hi[1], b_lo[2] + b_hi[2]],
+        );
+
+        // the full product is lo + (mid - lo - hi) * φ^3 + hi * φ^6 and has degree 10
+        let mut r = [BaseElement::ZERO; 11];
+        for i in 0..5 {
+            r[i] += lo[i];
+            r[i + 3] += mid[i] - lo[i] - hi[i];
+            r[i + 6] += hi[i];
+        }
+
+        // since φ^6 = 7, terms of degree 6 and higher are folded back into lower degree terms by
+        // multiplying them by 7
+        let w = BaseElement::new(7);
+        [
+            r[0] + w * r[6],
+            r[1] + w * r[7],
+            r[2] + w * r[8],
+            r[3] + w * r[9],
+            r[4] + w * r[10],
+            r[5],
+        ]
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 6]) -> [Self; 6] {
+        // φ^p = φ * 7^((p - 1) / 6); thus, the i-th coordinate is multiplied by
+        // 7^(i * (p - 1) / 6), and 7^((p - 1) / 2) = -1
+        [
+            x[0],
+            BaseElement::new(18446744065119617026) * x[1],
+            BaseElement::new(18446744065119617025) * x[2],
+            -x[3],
+            BaseElement::new(4294967295) * x[4],
+            BaseElement::new(4294967296) * x[5],
+        ]
+    }
 }
 
 // TYPE CONVERSIONS
@@ -523,6 +752,28 @@ impl Deserializable for BaseElement {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for BaseElement {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // always serialize the canonical representation of the element
+        serializer.serialize_u64(self.as_int())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BaseElement {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        if value >= M {
+            return Err(serde::de::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(BaseElement::new(value))
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -552,6 +803,30 @@ fn mod_reduce(x: u128) -> u64 {
     result.wrapping_add(E * (over as u64))
 }
 
+/// Reduces any value in [0, 2^64) range to [0, M) range without branching on the value.
+#[inline(always)]
+fn ct_normalize(value: u64) -> u64 {
+    // mask is all ones when value >= M and all zeros otherwise
+    let mask = 0u64.wrapping_sub((value >= M) as u64);
+    value.wrapping_sub(M & mask)
+}
+
+/// Multiplies two polynomials of degree 2 using Karatsuba multiplication; this requires 6
+/// multiplications in the base field.
+#[inline(always)]
+fn mul_deg2_polys(a: [BaseElement; 3], b: [BaseElement; 3]) -> [BaseElement; 5] {
+    let v0 = a[0] * b[0];
+    let v1 = a[1] * b[1];
+    let v2 = a[2] * b[2];
+    [
+        v0,
+        (a[0] + a[1]) * (b[0] + b[1]) - v0 - v1,
+        (a[0] + a[2]) * (b[0] + b[2]) - v0 - v2 + v1,
+        (a[1] + a[2]) * (b[1] + b[2]) - v1 - v2,
+        v2,
+    ]
+}
+
 /// Squares the base N number of times and multiplies the result by the tail value.
 #[inline(always)]
 fn exp_acc<const N: usize>(base: BaseElement, tail: BaseElement) -> BaseElement {
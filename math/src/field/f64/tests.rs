@@ -6,11 +6,14 @@
 use super::{
     AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField, E, M,
 };
-use crate::field::{CubeExtension, QuadExtension};
+use crate::field::{
+    traits::mul_with_irreducible, CubeExtension, ExtensibleField, QuadExtension, QuarticExtension,
+    SexticExtension,
+};
 use core::convert::TryFrom;
 use num_bigint::BigUint;
 use proptest::prelude::*;
-use rand_utils::rand_value;
+use rand_utils::{rand_array, rand_value};
 
 // MANUAL TESTS
 // ================================================================================================
@@ -135,6 +138,29 @@ fn equals() {
     assert_ne!(a.as_bytes(), b.as_bytes());
 }
 
+#[test]
+fn select() {
+    let a: BaseElement = rand_value();
+    let b: BaseElement = rand_value();
+    assert_eq!(a, BaseElement::select(a, b, false));
+    assert_eq!(b, BaseElement::select(a, b, true));
+}
+
+#[test]
+fn ct_eq() {
+    let a: BaseElement = rand_value();
+    let b: BaseElement = rand_value();
+    assert_eq!(a == b, a.ct_eq(&b));
+    assert!(a.ct_eq(&a));
+    assert!(!a.ct_eq(&(a + BaseElement::ONE)));
+
+    // elements with different internal representations of the same value are equal
+    let a = BaseElement::ZERO;
+    let b = -BaseElement::ONE + BaseElement::ONE;
+    assert_ne!(a.0, b.0);
+    assert!(a.ct_eq(&b));
+}
+
 // ROOTS OF UNITY
 // ------------------------------------------------------------------------------------------------
 
@@ -237,6 +263,47 @@ fn zeroed_vector() {
     }
 }
 
+#[test]
+fn extension_degree() {
+    const _: () = assert!(BaseElement::EXTENSION_DEGREE == 1);
+    const _: () = assert!(QuadExtension::<BaseElement>::EXTENSION_DEGREE == 2);
+    const _: () =
+        assert!(QuadExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 2);
+    const _: () = assert!(CubeExtension::<BaseElement>::EXTENSION_DEGREE == 3);
+    const _: () =
+        assert!(CubeExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 3);
+    const _: () = assert!(QuarticExtension::<BaseElement>::EXTENSION_DEGREE == 4);
+    const _: () =
+        assert!(QuarticExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 4);
+    const _: () = assert!(SexticExtension::<BaseElement>::EXTENSION_DEGREE == 6);
+    const _: () =
+        assert!(SexticExtension::<BaseElement>::ELEMENT_BYTES == BaseElement::ELEMENT_BYTES * 6);
+}
+
+#[test]
+fn irreducible_mul() {
+    // hand-written multiplication must match the default one derived from the polynomial
+    fn check<const N: usize>()
+    where
+        BaseElement: ExtensibleField<N>,
+    {
+        for _ in 0..100 {
+            let a = rand_array::<BaseElement, N>();
+            let b = rand_array::<BaseElement, N>();
+            let coeffs = <BaseElement as ExtensibleField<N>>::IRREDUCIBLE_COEFFS.unwrap();
+            assert_eq!(
+                mul_with_irreducible(a, b, &coeffs),
+                <BaseElement as ExtensibleField<N>>::mul(a, b)
+            );
+        }
+    }
+
+    check::<2>();
+    check::<3>();
+    check::<4>();
+    check::<6>();
+}
+
 // QUADRATIC EXTENSION
 // ------------------------------------------------------------------------------------------------
 #[test]
@@ -363,6 +430,604 @@ fn cube_mul() {
     assert_eq!(expected, a * b);
 }
 
+#[test]
+fn cube_frobenius_squared() {
+    // precomputed squared automorphism must match applying frobenius automorphism twice
+    for _ in 0..100 {
+        let x = rand_value::<CubeExtension<BaseElement>>().to_array();
+        let expected = <BaseElement as ExtensibleField<3>>::frobenius(
+            <BaseElement as ExtensibleField<3>>::frobenius(x),
+        );
+        assert_eq!(
+            expected,
+            <BaseElement as ExtensibleField<3>>::frobenius_squared(x)
+        );
+    }
+}
+
+#[test]
+fn cube_add_sub_slices() {
+    // 11 cubic elements give 33 base elements, which is not divisible by the SIMD width of 4;
+    // some values are non-canonical (i.e., internally stored as values >= M)
+    let mut a: Vec<CubeExtension<BaseElement>> = rand_utils::rand_vector(11);
+    let mut b: Vec<CubeExtension<BaseElement>> = rand_utils::rand_vector(11);
+    a[0] = CubeExtension::new(
+        -BaseElement::ONE + BaseElement::ONE,
+        BaseElement::ZERO,
+        E.into(),
+    );
+    b[0] = CubeExtension::new(BaseElement(M + 5), BaseElement(u64::MAX), BaseElement(M));
+    a[1] = CubeExtension::new(BaseElement(u64::MAX), BaseElement(M), BaseElement(M - 1));
+    b[1] = CubeExtension::new(
+        BaseElement(u64::MAX),
+        BaseElement(M - 1),
+        BaseElement(M + 1),
+    );
+
+    // results must be bit-identical to the scalar implementation, including non-canonical values
+    let raw_coeffs = |x: CubeExtension<BaseElement>| x.to_array().map(|c| c.0);
+    let sum = CubeExtension::add_slices(&a, &b);
+    let diff = CubeExtension::sub_slices(&a, &b);
+    for i in 0..a.len() {
+        assert_eq!(raw_coeffs(a[i] + b[i]), raw_coeffs(sum[i]));
+        assert_eq!(raw_coeffs(a[i] - b[i]), raw_coeffs(diff[i]));
+    }
+
+    // the vectorized path, if available, must produce the same results as the scalar path
+    #[cfg(target_arch = "x86_64")]
+    if super::avx2::is_available() {
+        let a = <CubeExtension<BaseElement> as FieldElement>::as_base_elements(&a);
+        let b = <CubeExtension<BaseElement> as FieldElement>::as_base_elements(&b);
+        for n in 0..a.len() {
+            let mut result = vec![BaseElement::ZERO; n];
+            unsafe { super::avx2::add(&a[..n], &b[..n], &mut result) };
+            for i in 0..n {
+                assert_eq!((a[i] + b[i]).0, result[i].0);
+            }
+
+            unsafe { super::avx2::sub(&a[..n], &b[..n], &mut result) };
+            for i in 0..n {
+                assert_eq!((a[i] - b[i]).0, result[i].0);
+            }
+        }
+    }
+}
+
+#[test]
+fn cube_mul_base_slice() {
+    // 11 cubic elements give 33 base elements, which is not divisible by the SIMD width of 4;
+    // the values include edge cases of the reduction, including non-canonical values
+    let mut values: Vec<CubeExtension<BaseElement>> = rand_utils::rand_vector(11);
+    values[0] = CubeExtension::new(BaseElement(u64::MAX), BaseElement(M), BaseElement(M - 1));
+    values[1] = CubeExtension::new(BaseElement(E), BaseElement(E + 1), BaseElement(1 << 63));
+    values[2] = CubeExtension::new(
+        BaseElement(0),
+        BaseElement(rand_value::<u64>()),
+        BaseElement(rand_value::<u64>()),
+    );
+
+    let raw_coeffs = |x: CubeExtension<BaseElement>| x.to_array().map(|c| c.0);
+    let scalars = [
+        BaseElement(u64::MAX),
+        BaseElement(M),
+        BaseElement(E),
+        BaseElement::ONE,
+        BaseElement::ZERO,
+        BaseElement(rand_value::<u64>()),
+        rand_value(),
+    ];
+    for &scalar in scalars.iter() {
+        // results must be bit-identical to the scalar implementation
+        let mut result = values.clone();
+        CubeExtension::mul_base_slice(&mut result, scalar);
+        for (&x, &y) in values.iter().zip(result.iter()) {
+            assert_eq!(raw_coeffs(x.mul_base(scalar)), raw_coeffs(y));
+        }
+
+        // the vectorized path, if available, must produce the same results as the scalar path
+        #[cfg(target_arch = "x86_64")]
+        if super::avx2::is_available() {
+            let coeffs = <CubeExtension<BaseElement> as FieldElement>::as_base_elements(&values);
+            for n in 0..coeffs.len() {
+                let mut result = coeffs[..n].to_vec();
+                unsafe { super::avx2::mul_scalar(&mut result, scalar) };
+                for i in 0..n {
+                    assert_eq!((coeffs[i] * scalar).0, result[i].0);
+                }
+            }
+        }
+    }
+}
+
+// QUARTIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn quartic_mul() {
+    // identity
+    let r: QuarticExtension<BaseElement> = rand_value();
+    assert_eq!(
+        <QuarticExtension<BaseElement>>::ZERO,
+        r * <QuarticExtension<BaseElement>>::ZERO
+    );
+    assert_eq!(r, r * <QuarticExtension<BaseElement>>::ONE);
+
+    // test multiplication within bounds
+    let a = <QuarticExtension<BaseElement>>::new(
+        BaseElement::new(3),
+        BaseElement::new(5),
+        BaseElement::new(2),
+        BaseElement::new(1),
+    );
+    let b = <QuarticExtension<BaseElement>>::new(
+        BaseElement::new(320),
+        BaseElement::new(68),
+        BaseElement::new(3),
+        BaseElement::new(7),
+    );
+    let expected = <QuarticExtension<BaseElement>>::new(
+        BaseElement::new(1723),
+        BaseElement::new(1923),
+        BaseElement::new(1038),
+        BaseElement::new(492),
+    );
+    assert_eq!(expected, a * b);
+
+    // test multiplication with overflow
+    let m = BaseElement::MODULUS;
+    let a = <QuarticExtension<BaseElement>>::new(
+        BaseElement::new(m - 1),
+        BaseElement::new(m - 3),
+        BaseElement::new(9223372034707292160),
+        BaseElement::new(m - 2),
+    );
+    let b = <QuarticExtension<BaseElement>>::new(
+        BaseElement::new(m - 5),
+        BaseElement::new(420),
+        BaseElement::new(m - 7),
+        BaseElement::new(11),
+    );
+    let expected = <QuarticExtension<BaseElement>>::new(
+        BaseElement::new(9223372034707286079),
+        BaseElement::new(9223372034707291815),
+        BaseElement::new(9223372034707290756),
+        BaseElement::new(18446744069414584131),
+    );
+    assert_eq!(expected, a * b);
+}
+
+#[test]
+fn quartic_conjugate() {
+    // applying frobenius automorphism four times must result in the original element
+    let a: QuarticExtension<BaseElement> = rand_value();
+    assert_eq!(a, a.conjugate().conjugate().conjugate().conjugate());
+    assert_ne!(a, a.conjugate());
+}
+
+// SEXTIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn sextic_mul() {
+    // identity
+    let r = SexticExtension::from(rand_array::<BaseElement, 6>());
+    assert_eq!(
+        <SexticExtension<BaseElement>>::ZERO,
+        r * <SexticExtension<BaseElement>>::ZERO
+    );
+    assert_eq!(r, r * <SexticExtension<BaseElement>>::ONE);
+
+    // test multiplication within bounds
+    let a = SexticExtension::from([1u8, 2, 3, 4, 5, 6].map(BaseElement::from));
+    let b = SexticExtension::from([7u8, 8, 9, 10, 11, 12].map(BaseElement::from));
+    let expected =
+        SexticExtension::from([1337u16, 1310, 1187, 962, 629, 182].map(BaseElement::from));
+    assert_eq!(expected, a * b);
+
+    // test multiplication with overflow
+    let m = BaseElement::MODULUS;
+    let a = SexticExtension::from(
+        [m - 1, m - 3, 9223372034707292160, m - 2, 5, m - 11].map(BaseElement::new),
+    );
+    let b = SexticExtension::from([m - 5, 420, m - 7, 11, m - 13, 17].map(BaseElement::new));
+    let expected = SexticExtension::from(
+        [
+            9223372034707259115,
+            9223372034707292802,
+            9223372034707289370,
+            1406,
+            9223372034707289970,
+            9223372034707294346,
+        ]
+        .map(BaseElement::new),
+    );
+    assert_eq!(expected, a * b);
+}
+
+#[test]
+fn sextic_conjugate() {
+    // frobenius automorphism must be the same as raising the element to the power of p
+    let a = SexticExtension::from(rand_array::<BaseElement, 6>());
+    assert_eq!(a.exp(BaseElement::MODULUS), a.conjugate());
+
+    // applying frobenius automorphism six times must result in the original element
+    let mut c = a.conjugate();
+    for _ in 0..5 {
+        assert_ne!(a, c);
+        c = c.conjugate();
+    }
+    assert_eq!(a, c);
+}
+
+// CUBIC EXTENSION TEST VECTORS
+// ------------------------------------------------------------------------------------------------
+
+// Test vectors for arithmetic in the cubic extension defined by x^3 - x - 1. All values are
+// canonical integer coordinates [α, β, γ]; the vectors were computed independently of this
+// library using schoolbook polynomial arithmetic over Python integers. The inputs include ZERO,
+// ONE, the multiplicative generator 2 + φ, powers of φ, -1, and values at the edges of the
+// reduction ranges.
+
+type CubeCoords = [u64; 3];
+
+/// Exponent used for exponentiation test vectors.
+const CUBE_KAT_EXPONENT: u64 = 0xdeadbeefcafebabe;
+
+/// (a, b, a + b, a * b)
+const CUBE_KAT_BINARY: [(CubeCoords, CubeCoords, CubeCoords, CubeCoords); 12] = [
+    ([0, 0, 0], [1, 0, 0], [1, 0, 0], [0, 0, 0]),
+    ([1, 0, 0], [2, 1, 0], [3, 1, 0], [2, 1, 0]),
+    ([2, 1, 0], [0, 1, 0], [2, 2, 0], [0, 2, 1]),
+    ([0, 1, 0], [0, 0, 1], [0, 1, 1], [1, 1, 0]),
+    (
+        [0, 0, 1],
+        [18446744069414584320, 0, 0],
+        [18446744069414584320, 0, 1],
+        [0, 0, 18446744069414584320],
+    ),
+    (
+        [18446744069414584320, 0, 0],
+        [
+            18446744069414584320,
+            18446744069414584320,
+            18446744069414584320,
+        ],
+        [
+            18446744069414584319,
+            18446744069414584320,
+            18446744069414584320,
+        ],
+        [1, 1, 1],
+    ),
+    (
+        [
+            18446744069414584320,
+            18446744069414584320,
+            18446744069414584320,
+        ],
+        [4294967296, 4294967295, 9223372036854775808],
+        [4294967295, 4294967294, 9223372036854775807],
+        [
+            9223372023969873922,
+            18446744052234715140,
+            18446744056529682435,
+        ],
+    ),
+    (
+        [4294967296, 4294967295, 9223372036854775808],
+        [
+            3178849313537249756,
+            14656720522449188240,
+            4446229937618225307,
+        ],
+        [
+            3178849317832217052,
+            14656720526744155535,
+            13669601974473001115,
+        ],
+        [12525038163651474075, 42485973959615358, 473328724915220578],
+    ),
+    (
+        [
+            3178849313537249756,
+            14656720522449188240,
+            4446229937618225307,
+        ],
+        [
+            11883716704162925960,
+            15303978370064951511,
+            7748930611531908249,
+        ],
+        [
+            15062566017700175716,
+            11513954823099555430,
+            12195160549150133556,
+        ],
+        [
+            4090557024706137667,
+            11563695380812622742,
+            12607985812602785981,
+        ],
+    ),
+    (
+        [
+            11883716704162925960,
+            15303978370064951511,
+            7748930611531908249,
+        ],
+        [
+            16884177452558073782,
+            13061990471924598696,
+            2518426005910772177,
+        ],
+        [
+            10321150087306415421,
+            9919224772574965886,
+            10267356617442680426,
+        ],
+        [
+            8238502272481302832,
+            8682483784417116045,
+            1841517183828644560,
+        ],
+    ),
+    (
+        [
+            16884177452558073782,
+            13061990471924598696,
+            2518426005910772177,
+        ],
+        [
+            8842813237171038051,
+            4524149371495277056,
+            7044900577426295661,
+        ],
+        [
+            7280246620314527512,
+            17586139843419875752,
+            9563326583337067838,
+        ],
+        [
+            7737195343159520692,
+            14759700417460554305,
+            16255441491706451978,
+        ],
+    ),
+    (
+        [
+            8842813237171038051,
+            4524149371495277056,
+            7044900577426295661,
+        ],
+        [2, 1, 0],
+        [
+            8842813237171038053,
+            4524149371495277057,
+            7044900577426295661,
+        ],
+        [6283782982353787442, 6489268488173303503, 167206456933284057],
+    ),
+];
+
+/// (a, a^-1, frobenius(a), a^CUBE_KAT_EXPONENT)
+const CUBE_KAT_UNARY: [(CubeCoords, CubeCoords, CubeCoords, CubeCoords); 12] = [
+    ([0, 0, 0], [0, 0, 0], [0, 0, 0], [0, 0, 0]),
+    ([1, 0, 0], [1, 0, 0], [1, 0, 0], [1, 0, 0]),
+    (
+        [2, 1, 0],
+        [
+            7905747458320536138,
+            13176245763867560229,
+            2635249152773512046,
+        ],
+        [
+            10615703402128488255,
+            10050274602728160328,
+            11746561000929144102,
+        ],
+        [
+            4288786009221671469,
+            15448403136599219536,
+            4605778533115886621,
+        ],
+    ),
+    (
+        [0, 1, 0],
+        [18446744069414584320, 0, 1],
+        [
+            10615703402128488253,
+            10050274602728160328,
+            11746561000929144102,
+        ],
+        [
+            3441811828384087290,
+            13979123852751852697,
+            10460808863485202536,
+        ],
+    ),
+    (
+        [0, 0, 1],
+        [1, 1, 18446744069414584320],
+        [
+            6700183068485440220,
+            14531223735771536287,
+            8396469466686423992,
+        ],
+        [
+            3776099911678087824,
+            1689476636389609597,
+            15355327618647887165,
+        ],
+    ),
+    (
+        [18446744069414584320, 0, 0],
+        [18446744069414584320, 0, 0],
+        [18446744069414584320, 0, 0],
+        [1, 0, 0],
+    ),
+    (
+        [
+            18446744069414584320,
+            18446744069414584320,
+            18446744069414584320,
+        ],
+        [18446744069414584319, 0, 1],
+        [
+            1130857598800655847,
+            12311989800329472027,
+            16750457671213600548,
+        ],
+        [
+            5103755487207579765,
+            2691549578051407790,
+            12035176438282159754,
+        ],
+    ),
+    (
+        [4294967296, 4294967295, 9223372036854775808],
+        [
+            10550720774825005617,
+            9529683282426764496,
+            13954179094272388803,
+        ],
+        [
+            3196113910915966677,
+            16424498191724057321,
+            13652573211630568897,
+        ],
+        [
+            16845007287904167013,
+            12579137932275511441,
+            16231800818839935888,
+        ],
+    ),
+    (
+        [
+            3178849313537249756,
+            14656720522449188240,
+            4446229937618225307,
+        ],
+        [
+            4690509544640882731,
+            1496233084076681012,
+            12903922787377958294,
+        ],
+        [
+            8329761298476496327,
+            2422431734521264355,
+            5943233994916647611,
+        ],
+        [
+            14311271340636011925,
+            2643428384989888983,
+            7018239481410674179,
+        ],
+    ),
+    (
+        [
+            11883716704162925960,
+            15303978370064951511,
+            7748930611531908249,
+        ],
+        [
+            3078039243057016335,
+            16565476431952182864,
+            11120676531027609376,
+        ],
+        [
+            14378329130955919867,
+            1203155150373060351,
+            13230384006049709549,
+        ],
+        [
+            14661200966885222970,
+            3808003123667271832,
+            4689717977566343239,
+        ],
+    ),
+    (
+        [
+            16884177452558073782,
+            13061990471924598696,
+            2518426005910772177,
+        ],
+        [
+            14720856859019260981,
+            18347237524601583371,
+            8094896828115030573,
+        ],
+        [
+            9267518149739590266,
+            11179741671001507182,
+            13943414960138497451,
+        ],
+        [
+            16995548363651558962,
+            2591915487997481636,
+            12847544320566833259,
+        ],
+    ),
+    (
+        [
+            8842813237171038051,
+            4524149371495277056,
+            7044900577426295661,
+        ],
+        [
+            16960932571211437022,
+            833307354122629640,
+            10339177550091127689,
+        ],
+        [
+            11591251327788795944,
+            7525187215893424577,
+            12145615476206950982,
+        ],
+        [
+            7487237330350703516,
+            17715398674840335254,
+            14129497586258953303,
+        ],
+    ),
+];
+
+#[test]
+fn cube_kat_add_mul() {
+    for &(a, b, sum, product) in CUBE_KAT_BINARY.iter() {
+        let (a, b) = (cube_from_coords(a), cube_from_coords(b));
+        check_cube_kat(sum, a + b);
+        check_cube_kat(product, a * b);
+        check_cube_kat(product, b * a);
+    }
+}
+
+#[test]
+fn cube_kat_inv_frobenius_exp() {
+    for &(a, inverse, frobenius, power) in CUBE_KAT_UNARY.iter() {
+        let a = cube_from_coords(a);
+        check_cube_kat(inverse, a.inv());
+        check_cube_kat(frobenius, a.frobenius());
+        check_cube_kat(frobenius, a.conjugate());
+        check_cube_kat(power, a.exp(CUBE_KAT_EXPONENT));
+    }
+
+    // the generator is the one exposed by the extension
+    assert_eq!(
+        cube_from_coords(CUBE_KAT_UNARY[2].0),
+        CubeExtension::multiplicative_generator()
+    );
+}
+
+fn cube_from_coords(coords: CubeCoords) -> CubeExtension<BaseElement> {
+    CubeExtension::from_ints(coords[0], coords[1], coords[2])
+}
+
+/// Checks that `actual` has the `expected` coordinates, and that its serialized form is the
+/// concatenation of the little-endian encodings of the coordinates.
+fn check_cube_kat(expected: CubeCoords, actual: CubeExtension<BaseElement>) {
+    assert_eq!(expected, actual.to_array().map(|c| c.as_int()));
+    let bytes: Vec<u8> = expected.iter().flat_map(|c| c.to_le_bytes()).collect();
+    assert_eq!(bytes, actual.to_bytes());
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -449,6 +1114,13 @@ proptest! {
         assert_eq!((v % super::M as u128) as u64, e.as_int());
     }
 
+    #[test]
+    fn reduce_le_bytes_proptest(bytes in prop::collection::vec(any::<u8>(), 0..100)) {
+        let expected = BigUint::from_bytes_le(&bytes) % BigUint::from(super::M);
+        let e = BaseElement::reduce_le_bytes(&bytes);
+        prop_assert_eq!(expected, BigUint::from(e.as_int()));
+    }
+
     // QUADRATIC EXTENSION
     // --------------------------------------------------------------------------------------------
     #[test]
@@ -466,6 +1138,12 @@ proptest! {
 
     // CUBIC EXTENSION
     // --------------------------------------------------------------------------------------------
+    #[test]
+    fn cube_square_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
+        let a = CubeExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2));
+        prop_assert_eq!(a * a, a.square());
+    }
+
     #[test]
     fn cube_mul_inv_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>()) {
         let a = CubeExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2));
@@ -478,4 +1156,19 @@ proptest! {
         };
         prop_assert_eq!(expected, a * b);
     }
+
+    // QUARTIC EXTENSION
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn quartic_mul_inv_proptest(a0 in any::<u64>(), a1 in any::<u64>(), a2 in any::<u64>(), a3 in any::<u64>()) {
+        let a = QuarticExtension::<BaseElement>::new(BaseElement::from(a0), BaseElement::from(a1), BaseElement::from(a2), BaseElement::from(a3));
+        let b = a.inv();
+
+        let expected = if a == QuarticExtension::<BaseElement>::ZERO {
+            QuarticExtension::<BaseElement>::ZERO
+        } else {
+            QuarticExtension::<BaseElement>::ONE
+        };
+        prop_assert_eq!(expected, a * b);
+    }
 }
@@ -0,0 +1,359 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! A toy prime field with modulus $7$, available only in tests.
+//!
+//! The field is small enough to enumerate all elements of its cubic extension (there are only
+//! $7^3 = 343$ of them), and thus, is used to exhaustively verify properties of generic extension
+//! field code. None of the operations are optimized or constant-time.
+//!
+//! Internally, the values are stored in the range $[0, 7)$ using `u64` as the backing type.
+
+use super::{
+    traits::{mul_with_irreducible, FieldElement, StarkField},
+    ExtensibleField,
+};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt::{Debug, Display, Formatter},
+    mem,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+use utils::{
+    collections::Vec, string::ToString, AsBytes, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Randomizable, Serializable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+// Field modulus = 7
+const M: u64 = 7;
+
+/// Number of bytes needed to represent field element
+const ELEMENT_BYTES: usize = core::mem::size_of::<u64>();
+
+// FIELD ELEMENT
+// ================================================================================================
+
+/// Represents base field element in the field.
+///
+/// Internal values are stored in the range [0, 7). The backing type is `u64`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct BaseElement(u64);
+
+impl BaseElement {
+    /// Creates a new field element from the provided `value`. If the value is greater than or
+    /// equal to the field modulus, modular reduction is silently performed.
+    pub const fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+}
+
+impl FieldElement for BaseElement {
+    type PositiveInteger = u64;
+    type BaseField = Self;
+
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+
+    const ELEMENT_BYTES: usize = ELEMENT_BYTES;
+    const IS_CANONICAL: bool = true;
+
+    fn exp(self, power: Self::PositiveInteger) -> Self {
+        let mut r = Self::ONE;
+        for i in (0..64).rev() {
+            r = r.square();
+            if (power >> i) & 1 == 1 {
+                r *= self;
+            }
+        }
+        r
+    }
+
+    fn inv(self) -> Self {
+        // by Fermat's little theorem, base^(M - 2) is the inverse of base; for ZERO this yields
+        // ZERO as well
+        self.exp(M - 2)
+    }
+
+    fn conjugate(&self) -> Self {
+        Self(self.0)
+    }
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        let p = elements.as_ptr();
+        let len = elements.len() * Self::ELEMENT_BYTES;
+        unsafe { slice::from_raw_parts(p as *const u8, len) }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        if (p as usize) % mem::align_of::<u64>() != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+
+    fn zeroed_vector(n: usize) -> Vec<Self> {
+        vec![Self::ZERO; n]
+    }
+
+    fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        elements
+    }
+}
+
+impl StarkField for BaseElement {
+    const MODULUS: Self::PositiveInteger = M;
+    const MODULUS_BITS: u32 = 3;
+
+    /// 3 generates the multiplicative group of order 6
+    const GENERATOR: Self = Self::new(3);
+
+    /// M - 1 = 2 * 3
+    const TWO_ADICITY: u32 = 1;
+
+    /// 3^3 = 6 = -1
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self::new(M - 1);
+
+    fn get_modulus_le_bytes() -> Vec<u8> {
+        M.to_le_bytes().to_vec()
+    }
+
+    fn as_int(&self) -> Self::PositiveInteger {
+        self.0
+    }
+}
+
+impl Randomizable for BaseElement {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        // only the three least significant bits are used; the value equal to M is rejected
+        let bytes: [u8; ELEMENT_BYTES] = bytes.try_into().ok()?;
+        let value = u64::from_le_bytes(bytes) & 7;
+        if value == M {
+            None
+        } else {
+            Some(Self(value))
+        }
+    }
+}
+
+impl Display for BaseElement {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl Add for BaseElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % M)
+    }
+}
+
+impl AddAssign for BaseElement {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for BaseElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0 + M - rhs.0) % M)
+    }
+}
+
+impl SubAssign for BaseElement {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for BaseElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self((self.0 * rhs.0) % M)
+    }
+}
+
+impl MulAssign for BaseElement {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Div for BaseElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for BaseElement {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl Neg for BaseElement {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self((M - self.0) % M)
+    }
+}
+
+// CUBIC EXTENSION
+// ================================================================================================
+
+// Coefficients of the irreducible polynomial x^3 - 2 over which the cubic extension is defined
+const CUBIC_IRREDUCIBLE_COEFFS: [BaseElement; 3] = [
+    BaseElement::new(2),
+    BaseElement::new(0),
+    BaseElement::new(0),
+];
+
+/// Defines a cubic extension of the base field over an irreducible polynomial x<sup>3</sup> - 2
+/// (the polynomial is irreducible because 2 is not a cube modulo 7). Thus, an extension element
+/// is defined as α + β * φ + γ * φ^2, where φ is a root of this polynomial, and α, β and γ are
+/// base field elements.
+impl ExtensibleField<3> for BaseElement {
+    const IRREDUCIBLE_COEFFS: Option<[Self; 3]> = Some(CUBIC_IRREDUCIBLE_COEFFS);
+
+    /// 1 + φ is a generator of the multiplicative group of order 7^3 - 1 = 2 * 3^2 * 19
+    const GENERATOR_COEFFS: Option<[Self; 3]> = Some([
+        BaseElement::new(1),
+        BaseElement::new(1),
+        BaseElement::new(0),
+    ]);
+
+    const SMALL_FIELD_ORDER: Option<u64> = Some(M);
+
+    #[inline(always)]
+    fn mul(a: [Self; 3], b: [Self; 3]) -> [Self; 3] {
+        // the field is only used for testing, and thus, the generic multiplication is good enough
+        mul_with_irreducible(a, b, &CUBIC_IRREDUCIBLE_COEFFS)
+    }
+
+    fn frobenius(x: [Self; 3]) -> [Self; 3] {
+        // φ^7 = φ * (φ^3)^2 = 4 * φ, and thus, (φ^2)^7 = 16 * φ^2 = 2 * φ^2
+        [x[0], BaseElement::new(4) * x[1], BaseElement::new(2) * x[2]]
+    }
+
+    fn sqrt_params() -> Option<(u32, &'static [u64])> {
+        // the order of the multiplicative group is 7^3 - 1 = 342 = 2 * 171
+        Some((1, &[171]))
+    }
+}
+
+// TYPE CONVERSIONS
+// ================================================================================================
+
+impl From<u128> for BaseElement {
+    fn from(value: u128) -> Self {
+        Self((value % (M as u128)) as u64)
+    }
+}
+
+impl From<u64> for BaseElement {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<u32> for BaseElement {
+    fn from(value: u32) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl From<u16> for BaseElement {
+    fn from(value: u16) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl From<u8> for BaseElement {
+    fn from(value: u8) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl TryFrom<&[u8]> for BaseElement {
+    type Error = DeserializationError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; ELEMENT_BYTES] = bytes.try_into().map_err(|_| {
+            DeserializationError::InvalidValue(format!(
+                "expected {} bytes for a field element, but was {} bytes",
+                ELEMENT_BYTES,
+                bytes.len(),
+            ))
+        })?;
+        let value = u64::from_le_bytes(bytes);
+        if value >= M {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl AsBytes for BaseElement {
+    fn as_bytes(&self) -> &[u8] {
+        let self_ptr: *const BaseElement = self;
+        unsafe { slice::from_raw_parts(self_ptr as *const u8, ELEMENT_BYTES) }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for BaseElement {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(&self.0.to_le_bytes());
+    }
+}
+
+impl Deserializable for BaseElement {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value = source.read_u64()?;
+        if value >= M {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(Self(value))
+    }
+}
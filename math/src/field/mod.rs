@@ -4,11 +4,17 @@
 // LICENSE file in the root directory of this source tree.
 
 mod traits;
-pub use traits::{ExtensibleField, FieldElement, StarkField};
+pub use traits::{
+    mul_with_irreducible, ExtensibleField, ExtensionOf, FieldElement, MontgomeryField, StarkField,
+};
 
 pub mod f128;
+pub mod f61;
 pub mod f62;
 pub mod f64;
 
+#[cfg(test)]
+pub(crate) mod f7;
+
 mod extensions;
-pub use extensions::{CubeExtension, QuadExtension};
+pub use extensions::{CubeExtension, QuadExtension, QuarticExtension, SexticExtension};
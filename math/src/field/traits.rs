@@ -6,13 +6,16 @@
 use core::{
     convert::TryFrom,
     fmt::{Debug, Display},
+    mem,
     ops::{
         Add, AddAssign, BitAnd, Div, DivAssign, Mul, MulAssign, Neg, Shl, Shr, ShrAssign, Sub,
         SubAssign,
     },
+    slice,
 };
 use utils::{
-    collections::Vec, AsBytes, Deserializable, DeserializationError, Randomizable, Serializable,
+    collections::Vec, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable, SliceReader,
 };
 
 // FIELD ELEMENT
@@ -25,7 +28,7 @@ use utils::{
 /// Moreover, it defines interfaces for serializing and deserializing field elements.
 ///
 /// The elements could be in a prime field or an extension of a prime field. Currently, only
-/// quadratic and cubic field extensions are supported.
+/// quadratic, cubic, quartic, and sextic field extensions are supported.
 pub trait FieldElement:
     Copy
     + Clone
@@ -78,6 +81,11 @@ pub trait FieldElement:
     /// Number of bytes needed to encode an element
     const ELEMENT_BYTES: usize;
 
+    /// Degree of this field as an extension of `Self::BaseField`; this is 1 for prime fields.
+    ///
+    /// `ELEMENT_BYTES` is always equal to `BaseField::ELEMENT_BYTES * EXTENSION_DEGREE`.
+    const EXTENSION_DEGREE: usize = 1;
+
     /// True if internal representation of the element is the same as its canonical representation.
     const IS_CANONICAL: bool;
 
@@ -111,6 +119,16 @@ pub trait FieldElement:
         self * self * self
     }
 
+    /// Returns a product of this field element and an element of the base field.
+    ///
+    /// The default implementation lifts `rhs` into this field and performs a full multiplication;
+    /// extension fields may override it to multiply their coordinates by `rhs` directly.
+    #[inline]
+    #[must_use]
+    fn mul_base(self, rhs: Self::BaseField) -> Self {
+        self * Self::from(rhs)
+    }
+
     /// Exponentiates this field element by `power` parameter.
     #[must_use]
     fn exp(self, power: Self::PositiveInteger) -> Self {
@@ -189,6 +207,34 @@ pub trait FieldElement:
     /// output list will contain decompositions of each extension element into underlying base
     /// elements.
     fn as_base_elements(elements: &[Self]) -> &[Self::BaseField];
+
+    /// Converts a mutable list of field elements into a mutable list of elements in the
+    /// underlying base field.
+    ///
+    /// This is the mutable counterpart of [FieldElement::as_base_elements()]: the returned slice
+    /// is a view into the same memory, and thus, any change made to it is reflected in the
+    /// source elements. Base elements of every extension element are laid out contiguously and
+    /// in coordinate order. Since the returned slice mutably borrows `elements`, the two cannot
+    /// be accessed at the same time.
+    ///
+    /// The default implementation reinterprets the memory of `elements` using the layout
+    /// described by [FieldElement::as_base_elements()].
+    ///
+    /// # Panics
+    /// Panics if the view returned by [FieldElement::as_base_elements()] does not cover exactly
+    /// the memory of `elements`.
+    fn as_base_elements_mut(elements: &mut [Self]) -> &mut [Self::BaseField] {
+        let base = Self::as_base_elements(elements);
+        let (base_ptr, len) = (base.as_ptr() as *const u8, base.len());
+        assert!(
+            base_ptr == elements.as_ptr() as *const u8
+                && len * mem::size_of::<Self::BaseField>() == mem::size_of_val(elements),
+            "base elements must be a view into the memory of the provided elements"
+        );
+        // the view covers exactly the memory of `elements`, which is borrowed mutably, and the
+        // base elements are already exposed through a shared view of the same memory
+        unsafe { slice::from_raw_parts_mut(elements.as_mut_ptr() as *mut Self::BaseField, len) }
+    }
 }
 
 // STARK FIELD
@@ -237,6 +283,130 @@ pub trait StarkField: FieldElement<BaseField = Self> {
 
     /// Returns a canonical integer representation of this field element.
     fn as_int(&self) -> Self::PositiveInteger;
+
+    /// Returns an element equal to this element with its internal representation reduced to a
+    /// unique form, so that equal elements have identical [AsBytes] representations.
+    ///
+    /// For fields whose internal representation is canonical, this is a no-op. Otherwise, the
+    /// default implementation round-trips the element through its canonical serialized form;
+    /// implementations are expected to override it with a cheaper reduction.
+    #[must_use]
+    fn normalize(self) -> Self {
+        if Self::IS_CANONICAL {
+            return self;
+        }
+        Self::read_from(&mut SliceReader::new(&self.to_bytes()))
+            .expect("canonical encoding of an element must be deserializable")
+    }
+
+    /// Returns a field element equal to the integer encoded by `bytes` in little-endian byte
+    /// order, reduced modulo the field modulus.
+    ///
+    /// Unlike [Randomizable::from_random_bytes()], this never rejects the input, and can be used
+    /// to map uniformly random inputs wider than the modulus into the field; the statistical
+    /// distance of the result from uniform is at most 2^-k, where k is the number of bits by which
+    /// the input exceeds [MODULUS_BITS](StarkField::MODULUS_BITS).
+    ///
+    /// The default implementation folds the input in 64-bit limbs using Horner's method.
+    fn reduce_le_bytes(bytes: &[u8]) -> Self {
+        let limb_shift = Self::from(1u128 << 64);
+        bytes.chunks(8).rev().fold(Self::ZERO, |acc, chunk| {
+            let mut limb = [0u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            acc * limb_shift + Self::from(u64::from_le_bytes(limb))
+        })
+    }
+
+    // CONSTANT-TIME OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `a` if `choice` is false, and `b` if `choice` is true.
+    ///
+    /// The selection must be performed without branching on `choice` or on the values of `a` and
+    /// `b`, so that the time it takes to execute this function does not depend on its inputs.
+    ///
+    /// The default implementation computes `a` + `choice` * (`b` - `a`) using field arithmetic,
+    /// and thus, is constant-time as long as the field operations are; implementations are
+    /// expected to override it with a cheaper mask-based selection over the internal values.
+    fn select(a: Self, b: Self, choice: bool) -> Self {
+        a + Self::from(choice as u64) * (b - a)
+    }
+
+    /// Returns true if this element and `other` represent the same field element.
+    ///
+    /// Unlike `==`, the comparison must be performed without branching on the values of the
+    /// elements, so that the time it takes to execute this function does not depend on its
+    /// inputs.
+    ///
+    /// The default implementation compares canonical byte encodings of the elements, accumulating
+    /// differences over all bytes; implementations are expected to override it with a cheaper
+    /// comparison over the internal values.
+    fn ct_eq(&self, other: &Self) -> bool {
+        let diff = self
+            .to_bytes()
+            .iter()
+            .zip(other.to_bytes().iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        diff == 0
+    }
+}
+
+// MONTGOMERY FIELD
+// ================================================================================================
+
+/// Defines a [StarkField] whose elements are stored internally in Montgomery form x * R mod p,
+/// where R = 2^(8 * [ELEMENT_BYTES](FieldElement::ELEMENT_BYTES)).
+///
+/// This exposes the Montgomery form for interoperability with libraries which encode field
+/// elements in this form. The encoding is distinct from the canonical encoding used by
+/// [Serializable], and the two must not be mixed.
+pub trait MontgomeryField: StarkField {
+    /// Writes the Montgomery form of this element reduced into the range [0, p) into the
+    /// `target` as [ELEMENT_BYTES](FieldElement::ELEMENT_BYTES) bytes in little-endian order.
+    fn write_montgomery_into<W: ByteWriter>(&self, target: &mut W);
+
+    /// Reads an element encoded in Montgomery form by
+    /// [write_montgomery_into()](MontgomeryField::write_montgomery_into) from the `source`.
+    ///
+    /// # Errors
+    /// Returns an error if the source does not contain enough bytes, or if the encoded value is
+    /// not in the range [0, p).
+    fn read_montgomery_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError>;
+}
+
+// EXTENSION OF
+// ================================================================================================
+
+/// Defines a field which is an extension of the [StarkField] `B` (including `B` itself, which is
+/// a degree 1 extension of itself).
+///
+/// This makes it possible to write code which is generic over the degree of the extension.
+pub trait ExtensionOf<B: StarkField>: FieldElement<BaseField = B> {
+    /// Returns the degree of this field over `B`.
+    fn base_degree() -> usize;
+
+    /// Returns an element of this field which represents the specified element of `B`.
+    fn from_base(b: B) -> Self;
+
+    /// Returns coordinates of this element over `B`; the length of the returned vector is equal
+    /// to [base_degree()](ExtensionOf::base_degree).
+    fn to_base_coords(&self) -> Vec<B>;
+}
+
+impl<B: StarkField> ExtensionOf<B> for B {
+    #[inline(always)]
+    fn base_degree() -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn from_base(b: B) -> Self {
+        b
+    }
+
+    fn to_base_coords(&self) -> Vec<B> {
+        vec![*self]
+    }
 }
 
 // EXTENSIBLE FIELD
@@ -245,20 +415,202 @@ pub trait StarkField: FieldElement<BaseField = Self> {
 /// Defines basic arithmetic in an extension of a StarkField of a given degree.
 ///
 /// This trait defines how to perform multiplication and compute a Frobenius automorphisms of an
-/// element in an extension of degree N for a given [StarkField]. It as assumed that an element in
+/// element in an extension of degree N for a given field. It as assumed that an element in
 /// degree N extension field can be represented by N field elements in the base field.
 ///
-/// Implementation of this trait implicitly defines the irreducible polynomial over which the
-/// extension field is defined.
-pub trait ExtensibleField<const N: usize>: StarkField {
+/// The field being extended is usually a [StarkField], but it could also be an extension field
+/// itself; this makes it possible to build towers of extensions (e.g., a cubic extension of a
+/// quadratic extension). In the latter case, the Frobenius automorphism is the one relative to
+/// the field being extended (i.e., x -> x^q, where q is the number of elements in that field).
+///
+/// The irreducible polynomial over which the extension field is defined may be specified via
+/// [IRREDUCIBLE_COEFFS](ExtensibleField::IRREDUCIBLE_COEFFS). Implementations which do not have a
+/// formula specialized for the polynomial can implement [mul()](ExtensibleField::mul) by calling
+/// [mul_with_irreducible()] with these coefficients.
+pub trait ExtensibleField<const N: usize>: FieldElement {
+    /// Coefficients [c_0, ..., c_(N-1)] of the irreducible polynomial x^N - c_(N-1) * x^(N-1) -
+    /// ... - c_1 * x - c_0 over which the extension field is defined; i.e., the root φ of the
+    /// polynomial satisfies φ^N = c_0 + c_1 * φ + ... + c_(N-1) * φ^(N-1).
+    ///
+    /// This is None by default, which means that the polynomial is not exposed to generic code.
+    const IRREDUCIBLE_COEFFS: Option<[Self; N]> = None;
+
+    /// Order of the field being extended if it is small enough for all elements of the extension
+    /// to be enumerated (e.g., for exhaustive testing), or None otherwise.
+    ///
+    /// This is None by default, and should be set only for toy fields.
+    const SMALL_FIELD_ORDER: Option<u64> = None;
+
+    /// Coefficients [g_0, ..., g_(N-1)] of a generator g = g_0 + g_1 * φ + ... + g_(N-1) *
+    /// φ^(N-1) of the multiplicative group of the extension field, or None if a generator is not
+    /// defined for this extension.
+    ///
+    /// The generator must have order p^N - 1, where p is the order of the field being extended;
+    /// i.e., raising it to (p^N - 1) / q must not yield ONE for any prime factor q of p^N - 1.
+    const GENERATOR_COEFFS: Option<[Self; N]> = None;
+
     /// Returns a product of `a` and `b` in the field defined by this extension.
     fn mul(a: [Self; N], b: [Self; N]) -> [Self; N];
 
+    /// Returns a product of `a` and an element `b` of the field being extended.
+    ///
+    /// The default implementation multiplies each coefficient of `a` by `b`, which requires N
+    /// multiplications in the base field; implementations may override it if multiplication by
+    /// elements of the field being extended can be performed more efficiently.
+    #[inline(always)]
+    fn mul_base(a: [Self; N], b: Self) -> [Self; N] {
+        let mut result = a;
+        for coeff in result.iter_mut() {
+            *coeff *= b;
+        }
+        result
+    }
+
+    /// Returns a square of `a` in the field defined by this extension.
+    ///
+    /// The default implementation multiplies `a` by itself; implementations may override it with
+    /// a specialized formula which requires fewer multiplications in the base field.
+    #[inline(always)]
+    fn square(a: [Self; N]) -> [Self; N] {
+        <Self as ExtensibleField<N>>::mul(a, a)
+    }
+
     /// Returns Frobenius automorphisms for `x` in the field defined by this extension.
     fn frobenius(x: [Self; N]) -> [Self; N];
 
+    /// Returns the result of applying the Frobenius automorphism to `x` twice.
+    ///
+    /// The default implementation applies [frobenius()](ExtensibleField::frobenius) twice;
+    /// implementations may override it with precomputed coefficients of the squared automorphism
+    /// so that the result can be computed directly from `x`.
+    #[inline(always)]
+    fn frobenius_squared(x: [Self; N]) -> [Self; N] {
+        <Self as ExtensibleField<N>>::frobenius(<Self as ExtensibleField<N>>::frobenius(x))
+    }
+
+    /// Returns the result of applying the Frobenius automorphism to `x` `k` times.
+    ///
+    /// Since the automorphism has order N in the field defined by this extension, `k` is reduced
+    /// modulo N first; the remaining applications are performed via
+    /// [frobenius_squared()](ExtensibleField::frobenius_squared) and
+    /// [frobenius()](ExtensibleField::frobenius). Thus, for N <= 3 the result is computed in at
+    /// most one step.
+    #[inline(always)]
+    fn frobenius_k(x: [Self; N], k: usize) -> [Self; N] {
+        let k = k % N;
+        let mut result = x;
+        for _ in 0..k / 2 {
+            result = <Self as ExtensibleField<N>>::frobenius_squared(result);
+        }
+        if k % 2 == 1 {
+            result = <Self as ExtensibleField<N>>::frobenius(result);
+        }
+        result
+    }
+
+    /// Writes pointwise sums of the coefficients in slices `a` and `b` into `result`.
+    ///
+    /// This is used to add slices of extension field elements via their flattened coefficient
+    /// representation. The default implementation adds coefficients one by one; implementations
+    /// may override it with a vectorized version, as long as the results are identical to the
+    /// ones produced by the default implementation.
+    ///
+    /// All three slices are expected to have the same length.
+    #[inline(always)]
+    fn add_coeffs(a: &[Self], b: &[Self], result: &mut [Self]) {
+        for ((r, &a), &b) in result.iter_mut().zip(a).zip(b) {
+            *r = a + b;
+        }
+    }
+
+    /// Writes pointwise differences of the coefficients in slices `a` and `b` into `result`.
+    ///
+    /// Same as [add_coeffs()](ExtensibleField::add_coeffs), but for subtraction.
+    #[inline(always)]
+    fn sub_coeffs(a: &[Self], b: &[Self], result: &mut [Self]) {
+        for ((r, &a), &b) in result.iter_mut().zip(a).zip(b) {
+            *r = a - b;
+        }
+    }
+
+    /// Multiplies all coefficients in slice `values` by `scalar` in place.
+    ///
+    /// This is used to multiply slices of extension field elements by a base field element via
+    /// their flattened coefficient representation. The default implementation multiplies
+    /// coefficients one by one; implementations may override it with a vectorized version, as
+    /// long as the results are identical to the ones produced by the default implementation.
+    #[inline(always)]
+    fn scale_coeffs(values: &mut [Self], scalar: Self) {
+        for value in values.iter_mut() {
+            *value *= scalar;
+        }
+    }
+
     /// Returns true if this extension is supported for the underlying base field.
     fn is_supported() -> bool {
         true
     }
+
+    /// Returns parameters of the multiplicative group of the field defined by this extension
+    /// which are needed to compute square roots, or None if square roots are not supported.
+    ///
+    /// The order of the multiplicative group is written as 2^`s` * `t`, where `t` is odd. The
+    /// returned tuple contains `s` and `t`, with `t` encoded as a sequence of 64-bit limbs in
+    /// little-endian order.
+    fn sqrt_params() -> Option<(u32, &'static [u64])> {
+        None
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a product of `a` and `b` reduced modulo the irreducible polynomial x^N - c_(N-1) *
+/// x^(N-1) - ... - c_0, where `coeffs` = [c_0, ..., c_(N-1)].
+///
+/// For N = 3 the product is computed via Karatsuba multiplication which requires 6 rather than 9
+/// multiplications (not counting the multiplications by the coefficients of the polynomial); for
+/// other degrees schoolbook multiplication is used. This can serve as an implementation of
+/// [ExtensibleField::mul()] for extensions which do not have a specialized formula.
+pub fn mul_with_irreducible<E: FieldElement, const N: usize>(
+    a: [E; N],
+    b: [E; N],
+    coeffs: &[E; N],
+) -> [E; N] {
+    let mut result = [E::ZERO; N];
+    if N == 3 {
+        let a0b0 = a[0] * b[0];
+        let a1b1 = a[1] * b[1];
+        let a2b2 = a[2] * b[2];
+
+        // coefficients of the unreduced product of degree 4
+        let d0 = a0b0;
+        let mut d1 = (a[0] + a[1]) * (b[0] + b[1]) - a0b0 - a1b1;
+        let mut d2 = (a[0] + a[2]) * (b[0] + b[2]) - a0b0 - a2b2 + a1b1;
+        let mut d3 = (a[1] + a[2]) * (b[1] + b[2]) - a1b1 - a2b2;
+        let d4 = a2b2;
+
+        // reduce φ^4 = φ * φ^3, and then φ^3
+        d1 += d4 * coeffs[0];
+        d2 += d4 * coeffs[1];
+        d3 += d4 * coeffs[2];
+
+        result[0] = d0 + d3 * coeffs[0];
+        result[1] = d1 + d3 * coeffs[1];
+        result[2] = d2 + d3 * coeffs[2];
+    } else {
+        // Horner's method over the coefficients of b: result = result * φ + a * b_i
+        for &b_i in b.iter().rev() {
+            let top = result[N - 1];
+            for j in (1..N).rev() {
+                result[j] = result[j - 1] + top * coeffs[j];
+            }
+            result[0] = top * coeffs[0];
+
+            for (r, &a_j) in result.iter_mut().zip(a.iter()) {
+                *r += a_j * b_i;
+            }
+        }
+    }
+    result
 }
@@ -32,23 +32,45 @@
 //!   properties. To achieve adequate security (i.e. ~100 bits), proofs must be generated in a
 //!   quadratic extension of this field. For higher levels of security, a cubic extension field
 //!   should be used.
+//! * A 61-bit field with the Mersenne modulus 2<sup>61</sup> - 1. Modular reduction in this field
+//!   requires only shifts and additions, but the two-adicity of the field is 1, and thus, the field
+//!   cannot be used for FFT-based operations over large domains. This field is intended primarily
+//!   for experimentation.
 //!
 //! ## Extension fields
 //!
-//! Currently, the library provides a generic way to create quadratic and cubic extensions of
-//! supported STARK fields. This can be done by implementing [ExtensibleField] trait for
-//! degrees 2 and 3.
+//! Currently, the library provides a generic way to create quadratic, cubic, quartic, and sextic
+//! extensions of supported STARK fields. This can be done by implementing [ExtensibleField] trait
+//! for degrees 2, 3, 4, and 6. Code which needs to work with any of the base field, its quadratic
+//! extension, or its cubic extension can be written generically over the [ExtensionOf] trait.
 //!
 //! Quadratic extension fields are defined using the following irreducible polynomials:
+//! * For [f61](crate::fields::f61) field, the polynomial is x<sup>2</sup> + 1.
 //! * For [f62](crate::fields::f62) field, the polynomial is x<sup>2</sup> - x - 1.
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>2</sup> - x + 2.
 //! * For [f128](crate::fields::f128) field, the polynomial is x<sup>2</sup> - x - 1.
 //!
 //! Cubic extension fields are defined using the following irreducible polynomials:
+//! * For [f61](crate::fields::f61) field, the polynomial is x<sup>3</sup> - 5.
 //! * For [f62](crate::fields::f62) field, the polynomial is x<sup>3</sup> + 2x + 2.
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>3</sup> - x - 1.
 //! * For [f128](crate::fields::f128) field, cubic extensions are not supported.
 //!
+//! Quartic extension fields are defined using the following irreducible polynomials:
+//! * For [f64](crate::fields::f64) field, the polynomial is x<sup>4</sup> - 7.
+//! * For [f61](crate::fields::f61), [f62](crate::fields::f62), and [f128](crate::fields::f128)
+//!   fields, quartic extensions are not supported.
+//!
+//! Sextic extension fields are defined using the following irreducible polynomials:
+//! * For [f64](crate::fields::f64) field, the polynomial is x<sup>6</sup> - 7.
+//! * For [f61](crate::fields::f61), [f62](crate::fields::f62), and [f128](crate::fields::f128)
+//!   fields, sextic extensions are not supported.
+//!
+//! Extensions can also be combined into towers. Specifically, a degree 6 extension can also be
+//! built as a cubic extension of a quadratic extension (i.e., `CubeExtension<QuadExtension<B>>`)
+//! for base fields which support both quadratic and cubic extensions. Such a cubic extension is
+//! defined using the same cubic polynomial as the one used for the base field.
+//!
 //! # Polynomials
 //! [Polynomials](polynom) module implements basic polynomial operations such as:
 //!
@@ -97,7 +119,9 @@ pub mod fft;
 pub mod polynom;
 
 mod field;
-pub use field::{ExtensibleField, FieldElement, StarkField};
+pub use field::{
+    mul_with_irreducible, ExtensibleField, ExtensionOf, FieldElement, MontgomeryField, StarkField,
+};
 pub mod fields {
     //! Finite field implementations.
     //!
@@ -105,10 +129,13 @@ pub mod fields {
     //! of these field.
 
     pub use super::field::f128;
+    pub use super::field::f61;
     pub use super::field::f62;
     pub use super::field::f64;
     pub use super::field::CubeExtension;
     pub use super::field::QuadExtension;
+    pub use super::field::QuarticExtension;
+    pub use super::field::SexticExtension;
 }
 
 mod utils;
@@ -635,7 +635,7 @@ where
 
 // HELPER FUNCTIONS
 // ================================================================================================
-fn get_zero_roots<E: FieldElement>(xs: &[E]) -> Vec<E> {
+pub(crate) fn get_zero_roots<E: FieldElement>(xs: &[E]) -> Vec<E> {
     let mut result = unsafe { utils::uninit_vector(xs.len() + 1) };
     fill_zero_roots(xs, &mut result);
     result
@@ -0,0 +1,44 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Makes sure that extension fields can be used from crates which do not link the Rust standard
+//! library. To check this against the `no_std` build of the crate, run:
+//!
+//! `cargo test -p winter-math --no-default-features --test no_std`
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use utils::{Deserializable, Serializable, SliceReader};
+use winter_math::{
+    fields::{f64::BaseElement, CubeExtension},
+    FieldElement,
+};
+
+#[test]
+fn cube_extension_no_std() {
+    let a = CubeExtension::<BaseElement>::from_ints(1, 2, 3);
+    let b = CubeExtension::<BaseElement>::from_ints(4, 5, 6);
+    let c = a * b + a.inv();
+    assert_eq!(CubeExtension::<BaseElement>::ONE, a * a.inv());
+
+    // serialize and deserialize the element
+    let bytes = c.to_bytes();
+    assert_eq!(CubeExtension::<BaseElement>::ELEMENT_BYTES, bytes.len());
+    let mut reader = SliceReader::new(&bytes);
+    assert_eq!(c, CubeExtension::read_from(&mut reader).unwrap());
+
+    // serialize and deserialize a vector of elements
+    let elements: Vec<CubeExtension<BaseElement>> = CubeExtension::from_int_triples(&[(1, 2, 3)]);
+    let mut bytes = Vec::new();
+    CubeExtension::write_vec_into(&elements, &mut bytes);
+    let mut reader = SliceReader::new(&bytes);
+    assert_eq!(elements, CubeExtension::read_vec_from(&mut reader).unwrap());
+
+    // the canonical string representation relies on alloc only
+    assert_eq!("(1, 2, 3)", a.to_canonical_string());
+}